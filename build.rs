@@ -0,0 +1,163 @@
+// Copyright (C) 2026  Antonio-Miguel Corbi Bellot
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+// Genera los estáticos VS/FS de los modelos embebidos a partir de los
+// ficheros OBJ listados en assets/models/models.txt. El resultado se
+// escribe en $OUT_DIR/models.rs y se incluye desde src/models.rs
+//
+// De los OBJ sólo se acepta un subconjunto:
+//  - "v x y z [w]": posiciones; la w se ignora.
+//  - "f i j k ...": polígonos de 3 o más vértices, tal cual (sin
+//    triangular). Cada índice puede ser "v", "v/vt", "v//vn" o "v/vt/vn"
+//    (sólo se usa la posición); los negativos son relativos al final.
+//  - Los comentarios con '#' y las líneas vacías.
+// El resto de directivas (vt, vn, l, g, o, s, usemtl, mtllib...) se
+// ignoran, y las líneas continuadas con '\' no se admiten. Para lo
+// demás está el lector de src/files/obj.rs en tiempo de ejecución
+
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const MODELS_DIR: &str = "assets/models";
+const MODELS_LIST: &str = "assets/models/models.txt";
+
+// -- Lectura de la lista de modelos: -------------------------------------
+fn read_list(fname: &Path) -> Vec<(String, PathBuf)> {
+    let text = fs::read_to_string(fname).unwrap_or_else(|e| panic!("{}: {e}", fname.display()));
+    let mut models: Vec<(String, PathBuf)> = vec![];
+    for (n, line) in text.lines().enumerate() {
+        let line = line.split('#').next().unwrap_or_default();
+        let toks: Vec<&str> = line.split_whitespace().collect();
+        match toks[..] {
+            [] => {}
+            [name, obj] => {
+                let valid = name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+                    && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+                if !valid {
+                    panic!(
+                        "{}:{}: '{name}' is not a valid model name",
+                        fname.display(),
+                        n + 1
+                    );
+                }
+                if models.iter().any(|(m, _)| m == name) {
+                    panic!("{}:{}: duplicate model '{name}'", fname.display(), n + 1);
+                }
+                models.push((name.to_string(), Path::new(MODELS_DIR).join(obj)));
+            }
+            _ => panic!(
+                "{}:{}: expected '<name> <file.obj>'",
+                fname.display(),
+                n + 1
+            ),
+        }
+    }
+    models
+}
+
+// -- Lectura del OBJ: ----------------------------------------------------
+// Sólo interesan los vértices y las caras, que se conservan como polígonos
+fn read_obj(fname: &Path) -> (Vec<[f32; 3]>, Vec<Vec<u32>>) {
+    let text = fs::read_to_string(fname).unwrap_or_else(|e| panic!("{}: {e}", fname.display()));
+    let fail = |n: usize, msg: &str| -> ! { panic!("{}:{}: {msg}", fname.display(), n + 1) };
+
+    let mut vs = vec![];
+    let mut fs = vec![];
+    for (n, line) in text.lines().enumerate() {
+        let line = line.split('#').next().unwrap_or_default();
+        let mut toks = line.split_whitespace();
+        match toks.next() {
+            Some("v") => {
+                let xyz: Vec<f32> = toks
+                    .take(3)
+                    .map(|t| t.parse().unwrap_or_else(|_| fail(n, "invalid number")))
+                    .collect();
+                if xyz.len() < 3 {
+                    fail(n, "expected 3 coordinates");
+                }
+                vs.push([xyz[0], xyz[1], xyz[2]]);
+            }
+            Some("f") => {
+                // "v", "v/vt", "v//vn" o "v/vt/vn"; los negativos son relativos
+                let idx: Vec<u32> = toks
+                    .map(|t| {
+                        let i: i64 = t
+                            .split('/')
+                            .next()
+                            .and_then(|s| s.parse().ok())
+                            .unwrap_or_else(|| fail(n, "invalid index"));
+                        let i = if i < 0 { vs.len() as i64 + i } else { i - 1 };
+                        if i < 0 || i as usize >= vs.len() {
+                            fail(n, "index out of range");
+                        }
+                        i as u32
+                    })
+                    .collect();
+                if idx.len() < 3 {
+                    fail(n, "expected at least 3 indices");
+                }
+                fs.push(idx);
+            }
+            _ => {}
+        }
+    }
+    (vs, fs)
+}
+
+// -- Generación del código: ----------------------------------------------
+fn emit(out: &mut String, name: &str, vs: &[[f32; 3]], fs: &[Vec<u32>]) {
+    writeln!(out, "pub mod {name} {{").unwrap();
+    writeln!(out, "    use crate::types::{{Lines, Point3D, Points}};").unwrap();
+    writeln!(out, "    pub static VS: Points = &[").unwrap();
+    for [x, y, z] in vs {
+        writeln!(out, "        Point3D {{ x: {x:?}, y: {y:?}, z: {z:?} }},").unwrap();
+    }
+    writeln!(out, "    ];").unwrap();
+    writeln!(out, "    pub static FS: Lines = &[").unwrap();
+    for f in fs {
+        let idx: Vec<String> = f.iter().map(u32::to_string).collect();
+        writeln!(out, "        &[{}],", idx.join(", ")).unwrap();
+    }
+    writeln!(out, "    ];").unwrap();
+    writeln!(out, "}}").unwrap();
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed={MODELS_LIST}");
+    let models = read_list(Path::new(MODELS_LIST));
+
+    let mut out = String::new();
+    for (name, obj) in &models {
+        println!("cargo:rerun-if-changed={}", obj.display());
+        let (vs, fs) = read_obj(obj);
+        emit(&mut out, name, &vs, &fs);
+    }
+
+    // Tabla con todos los modelos, en el orden de la lista
+    writeln!(
+        out,
+        "pub static MODELS: &[(&str, crate::types::Points, crate::types::Lines)] = &["
+    )
+    .unwrap();
+    for (name, _) in &models {
+        writeln!(out, "    (\"{name}\", {name}::VS, {name}::FS),").unwrap();
+    }
+    writeln!(out, "];").unwrap();
+
+    let dst = Path::new(&env::var("OUT_DIR").unwrap()).join("models.rs");
+    fs::write(&dst, out).unwrap_or_else(|e| panic!("{}: {e}", dst.display()));
+}
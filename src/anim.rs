@@ -0,0 +1,252 @@
+// Copyright (C) 2026  Antonio-Miguel Corbi Bellot
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+// Grabación del giro del modelo: secuencia numerada de PNG o GIF animado,
+// fotograma a fotograma con el rasterizador en memoria
+
+use std::io::{self, Write};
+use std::path::Path;
+
+use egui::{Color32, Rect, pos2, vec2};
+
+use crate::files::create;
+use crate::raster::Canvas;
+use crate::render::{self, View};
+use crate::types::{Axe, Mesh};
+
+// Límites de una grabación: se hace de una vez (en el visor, en el hilo de
+// la interfaz), así que ni demasiados fotogramas ni pasos tan pequeños que
+// una vuelta necesite más de MAX_FRAMES
+pub const MAX_FRAMES: usize = 3600;
+pub const MIN_STEP: f32 = 0.1;
+pub const MAX_STEP: f32 = 360.0;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AnimOptions {
+    pub width: usize,
+    pub height: usize,
+    pub frames: usize,
+    // Grados que gira el modelo entre un fotograma y el siguiente
+    pub step: f32,
+    pub fps: f32,
+    // Veces que se repite el GIF; None: sin fin
+    pub loops: Option<u16>,
+    pub background: Color32,
+}
+
+impl Default for AnimOptions {
+    fn default() -> Self {
+        Self {
+            width: 400,
+            height: 300,
+            frames: 36,
+            step: 10.0,
+            fps: 12.0,
+            loops: None,
+            background: Color32::from_rgb(50, 50, 50),
+        }
+    }
+}
+
+impl AnimOptions {
+    // Fotogramas y paso para la combinación que se haya indicado: los dos,
+    // sólo uno (el otro completa una vuelta) o ninguno. Un paso negativo
+    // gira en sentido contrario; uno nulo no da ninguna vuelta
+    pub fn turn(frames: Option<usize>, step: Option<f32>) -> (usize, f32) {
+        let frames = frames.map(|n| n.clamp(1, MAX_FRAMES));
+        match (frames, step) {
+            (Some(n), Some(s)) => (n, s),
+            (Some(n), None) => (n, 360.0 / n as f32),
+            (None, Some(s)) if s != 0.0 => {
+                let s = clamp_step(s);
+                (((360.0 / s.abs()).round() as usize).max(1), s)
+            }
+            _ => {
+                let d = Self::default();
+                (d.frames, d.step)
+            }
+        }
+    }
+}
+
+// Paso entre MIN_STEP y MAX_STEP grados, con su signo (sentido de giro)
+pub fn clamp_step(step: f32) -> f32 {
+    step.abs().clamp(MIN_STEP, MAX_STEP).copysign(step)
+}
+
+// Graba la animación: GIF si `fname` termina en .gif y, si no, un PNG por
+// fotograma (modelo.png → modelo_0000.png, modelo_0001.png, …). A partir
+// de la vista se gira en los ejes `axes` o, si no hay ninguno, en Y.
+// Devuelve cuántos ficheros se han escrito
+pub fn record(
+    fname: &str,
+    mesh: &Mesh,
+    view: &View,
+    axes: &[Axe],
+    opts: &AnimOptions,
+) -> io::Result<usize> {
+    if !(1..=MAX_FRAMES).contains(&opts.frames) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("the number of frames must be in 1..={MAX_FRAMES}"),
+        ));
+    }
+    let is_gif = Path::new(fname)
+        .extension()
+        .is_some_and(|e| e.eq_ignore_ascii_case("gif"));
+    let axes = if axes.is_empty() { &[Axe::Y][..] } else { axes };
+    let frames = (0..opts.frames).map(|i| frame(mesh, view, axes, opts, i as f32 * opts.step));
+    if is_gif {
+        write_gif(fname, frames, opts)?;
+        Ok(1)
+    } else {
+        for (i, canvas) in frames.enumerate() {
+            canvas.write_png(&numbered(fname, i))?;
+        }
+        Ok(opts.frames)
+    }
+}
+
+// Fotograma con el modelo girado en `axes` `delta` grados más que en la
+// vista
+fn frame(mesh: &Mesh, view: &View, axes: &[Axe], opts: &AnimOptions, delta: f32) -> Canvas {
+    let mut view = view.clone();
+    for &axe in axes {
+        match view.rotation.iter_mut().find(|(a, _)| *a == axe) {
+            Some((_, angle)) => *angle = (*angle + delta) % 360.0,
+            None => view.rotation.push((axe, delta)),
+        }
+    }
+    let (w, h) = (opts.width, opts.height);
+    let screenr = Rect::from_min_size(pos2(0.0, 0.0), vec2(w as f32, h as f32));
+    let mut canvas = Canvas::new(w, h, opts.background);
+    canvas.draw(&render::build(mesh, &view, screenr));
+    canvas
+}
+
+fn numbered(fname: &str, i: usize) -> String {
+    let path = Path::new(fname);
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    let ext = path
+        .extension()
+        .map_or("png".into(), |e| e.to_string_lossy());
+    path.with_file_name(format!("{stem}_{i:04}.{ext}"))
+        .to_string_lossy()
+        .into_owned()
+}
+
+fn write_gif(
+    fname: &str,
+    frames: impl Iterator<Item = Canvas>,
+    opts: &AnimOptions,
+) -> io::Result<()> {
+    let (w, h) = (opts.width as u16, opts.height as u16);
+    if w as usize != opts.width || h as usize != opts.height {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "GIF images are at most 65535x65535",
+        ));
+    }
+    let mut file = create(fname)?;
+    let mut encoder = gif::Encoder::new(&mut file, w, h, &[]).map_err(io::Error::other)?;
+    encoder
+        .set_repeat(match opts.loops {
+            Some(n) => gif::Repeat::Finite(n),
+            None => gif::Repeat::Infinite,
+        })
+        .map_err(io::Error::other)?;
+    // El retardo se mide en centésimas de segundo
+    let delay = (100.0 / opts.fps.max(0.01))
+        .round()
+        .clamp(1.0, u16::MAX as f32) as u16;
+    for canvas in frames {
+        let mut rgba = canvas.to_rgba();
+        let mut frame = gif::Frame::from_rgba_speed(w, h, &mut rgba, 10);
+        frame.delay = delay;
+        encoder.write_frame(&frame).map_err(io::Error::other)?;
+    }
+    drop(encoder);
+    file.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{TempDir, view};
+
+    #[test]
+    fn frames_and_step_complete_a_turn() {
+        assert_eq!(AnimOptions::turn(None, None), (36, 10.0));
+        assert_eq!(AnimOptions::turn(Some(90), None), (90, 4.0));
+        assert_eq!(AnimOptions::turn(None, Some(5.0)), (72, 5.0));
+        assert_eq!(AnimOptions::turn(Some(3), Some(1.0)), (3, 1.0));
+        // El signo del paso se conserva
+        assert_eq!(AnimOptions::turn(None, Some(-10.0)), (36, -10.0));
+        assert_eq!(AnimOptions::turn(None, Some(-7.0)), (51, -7.0));
+        // Con paso nulo se usa la vuelta por defecto
+        assert_eq!(AnimOptions::turn(None, Some(0.0)), (36, 10.0));
+        assert_eq!(AnimOptions::turn(None, Some(-0.0)), (36, 10.0));
+        // Ni más de MAX_FRAMES fotogramas ni pasos menores que MIN_STEP
+        assert_eq!(AnimOptions::turn(Some(1_000_000), None).0, MAX_FRAMES);
+        assert_eq!(AnimOptions::turn(None, Some(0.001)), (MAX_FRAMES, MIN_STEP));
+        assert_eq!(
+            AnimOptions::turn(None, Some(-0.001)),
+            (MAX_FRAMES, -MIN_STEP)
+        );
+        assert_eq!(clamp_step(-720.0), -MAX_STEP);
+    }
+
+    #[test]
+    fn png_sequence_and_gif() {
+        let opts = AnimOptions {
+            width: 32,
+            height: 24,
+            frames: 4,
+            step: 90.0,
+            ..AnimOptions::default()
+        };
+        let mesh = Mesh::penger();
+        let tmp = TempDir::new();
+        let dst = tmp.file("spin.png");
+        assert_eq!(record(&dst, &mesh, &view(), &[], &opts).unwrap(), 4);
+        for i in 0..4 {
+            assert!(Path::new(&numbered(&dst, i)).exists());
+        }
+
+        let dst = tmp.file("spin.gif");
+        record(&dst, &mesh, &view(), &[], &opts).unwrap();
+        let mut options = gif::DecodeOptions::new();
+        options.set_color_output(gif::ColorOutput::RGBA);
+        let mut decoder = options
+            .read_info(std::fs::File::open(&dst).unwrap())
+            .unwrap();
+        assert_eq!((decoder.width(), decoder.height()), (32, 24));
+        let mut frames = 0;
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            assert_eq!(frame.delay, 8);
+            frames += 1;
+        }
+        assert_eq!(frames, 4);
+
+        // Las grabaciones demasiado largas se rechazan antes de empezar
+        let long = AnimOptions {
+            frames: MAX_FRAMES + 1,
+            ..opts
+        };
+        let dst = tmp.file("long.gif");
+        assert!(record(&dst, &mesh, &view(), &[], &long).is_err());
+        assert!(!Path::new(&dst).exists());
+    }
+}
@@ -14,15 +14,37 @@
 // along with this program.  If not, see <http://www.gnu.org/licenses/>.
 
 // -- Uses: ---------------------------------------------------------------
-use crate::types::{Axe, Point2D, Point3D};
-use egui::{pos2, remap, Color32, Pos2, Rect, Stroke};
+use crate::anim::{self, AnimOptions};
+use crate::camera::{self, Camera, Projection, ViewPreset};
+use crate::cli::{Cli, RotateAxis};
+use crate::files::{self, LoadError, PlyEncoding, SaveOptions};
+use crate::math::Vec3;
+use crate::render::{self, Light, Primitive, RenderList, ScreenPoint, View};
+use crate::svg::{self, SvgOptions};
+use crate::types::{Axe, Mesh, Point3D};
+use egui::{Color32, Rect, Shape, Stroke, pos2};
 
 // -- Constants: ----------------------------------------------------------
-const MIN_ZOOM: f32 = 0.25;
-const MAX_ZOOM: f32 = 15.00;
+// Duración del fotograma que avanza "Step" con la animación en pausa
+const STEP_DT: f32 = 1.0 / 60.0;
 
-const MIN_ANGLE_STEP: f32 = 0.00;
-const MAX_ANGLE_STEP: f32 = 10.00;
+const BACKGROUND: Color32 = Color32::from_rgb(50, 50, 50);
+
+// Ratón: zoom relativo por cada punto de desplazamiento de la rueda,
+// frenado del giro por inercia (por segundo), velocidad mínima a la que se
+// detiene (grados por segundo) y tolerancia, en píxeles, al elegir un
+// vértice
+const SCROLL_ZOOM: f32 = 0.0025;
+const SPIN_DAMPING: f32 = 3.0;
+const MIN_SPIN: f32 = 1.0;
+const PICK_PIXELS: f32 = 6.0;
+
+// Radio, en píxeles, de los puntos que marcan los vértices
+const POINT_RADIUS: f32 = 1.5;
+
+// Ficheros recientes: cuántos se recuerdan y con qué clave se guardan
+const MAX_RECENT: usize = 10;
+const RECENT_KEY: &str = "recent_models";
 
 // -- Structs: ------------------------------------------------------------
 pub struct App3D {
@@ -31,8 +53,56 @@ pub struct App3D {
     rotz: bool,
     draw_vs: bool,
     draw_fs: bool,
+    draw_bbox: bool,
+    // Caras rellenas con sombreado plano
+    solid: bool,
+    light: Light,
+    // Velocidad de giro en grados por segundo
     angle_step: f32,
+    // Ángulo de giro actual en cada eje (X, Y, Z), en grados
+    angles: [f32; 3],
+    playing: bool,
+    reverse: bool,
+    // Aumento respecto a la vista encajada: la cámara está a
+    // `fit_distance / zoom` del objetivo
     zoom: f32,
+    fit_distance: f32,
+    camera: Camera,
+    show_camera: bool,
+    // Giro por inercia de la cámara tras soltar el ratón: eje y velocidad
+    // en grados por segundo
+    spin: Option<(Vec3, f32)>,
+    background: Color32,
+    mesh: Mesh,
+    model_path: String,
+    error: Option<String>,
+    save_dialog: Option<SaveDialog>,
+    svg_dialog: Option<SvgDialog>,
+    anim_dialog: Option<AnimDialog>,
+    recent: Vec<String>,
+    // Encaje del modelo en la vista: centro y escala
+    center: Point3D,
+    scale: f32,
+    // Área de dibujo del último fotograma
+    view_rect: Rect,
+}
+
+// Estado de la ventana "Save As…"
+struct SaveDialog {
+    path: String,
+    opts: SaveOptions,
+}
+
+// Estado de la ventana "Export SVG…"
+struct SvgDialog {
+    path: String,
+    opts: SvgOptions,
+}
+
+// Estado de la ventana "Record Animation…"
+struct AnimDialog {
+    path: String,
+    opts: AnimOptions,
 }
 
 // -- Implementation App3D: -----------------------------------------------
@@ -44,160 +114,791 @@ impl App3D {
             rotz: false,
             draw_vs: false,
             draw_fs: true,
+            draw_bbox: false,
+            solid: false,
+            light: Light::default(),
             angle_step: 0.0,
+            angles: [0.0; 3],
+            playing: true,
+            reverse: false,
             zoom: 1.0,
+            fit_distance: Camera::default().distance(),
+            camera: Camera::default(),
+            show_camera: false,
+            spin: None,
+            background: BACKGROUND,
+            mesh: Mesh::penger(),
+            model_path: String::new(),
+            error: None,
+            save_dialog: None,
+            svg_dialog: None,
+            anim_dialog: None,
+            recent: vec![],
+            center: Point3D {
+                x: 0.0,
+                y: 0.0,
+                z: 0.0,
+            },
+            scale: 1.0,
+            view_rect: Rect::NOTHING,
         }
     }
 
-    fn draw_circle(&self, painter: &egui::Painter) {
-        // Obtener las dimensiones
-        // let width = painter.clip_rect().width();
-        // let height = painter.clip_rect().height();
+    // Recupera la lista de ficheros recientes de la sesión anterior
+    pub fn with_storage(mut self, storage: Option<&dyn eframe::Storage>) -> Self {
+        if let Some(recent) = storage.and_then(|s| eframe::get_value(s, RECENT_KEY)) {
+            self.recent = recent;
+        }
+        self.fit_view();
+        self
+    }
 
-        let world: Rect = Rect::from_min_max(pos2(0.0, 0.0), pos2(100.0, 100.0));
-        let screen = painter.clip_rect();
-        let scrx = remap(50.0, world.min.x..=world.max.x, screen.min.x..=screen.max.x);
-        let scry = remap(50.0, world.min.y..=world.max.y, screen.min.y..=screen.max.y);
+    // Aplica las opciones de la línea de órdenes
+    pub fn with_cli(mut self, cli: &Cli) -> Self {
+        if let Some(fname) = &cli.model {
+            self.error = self.load_model(fname).err().map(|e| e.to_string());
+        }
+        if let Some(axes) = &cli.rotate {
+            self.rotx = axes.contains(&RotateAxis::X);
+            self.roty = axes.contains(&RotateAxis::Y);
+            self.rotz = axes.contains(&RotateAxis::Z);
+        }
+        if let Some(step) = cli.angle_step {
+            self.angle_step = step;
+        }
+        if let Some(zoom) = cli.zoom {
+            self.set_zoom(zoom);
+        }
+        if cli.wireframe || cli.points {
+            self.draw_fs = cli.wireframe;
+            self.draw_vs = cli.points;
+        }
+        if let Some(color) = cli.background {
+            self.background = color;
+        }
+        self
+    }
 
-        // También puedes obtener los límites
-        // let min = painter.clip_rect().min; // Esquina superior izquierda (Pos2)
-        // let max = painter.clip_rect().max; // Esquina inferior derecha (Pos2)
+    // Carga un modelo desde disco sustituyendo la malla actual
+    pub fn load_model(&mut self, fname: &str) -> Result<(), LoadError> {
+        let loaded = files::load_mesh(fname);
+        // Un fichero que ya no se puede leer deja de ser reciente
+        self.recent.retain(|r| r != fname);
+        self.mesh = loaded?;
+        self.model_path = fname.to_string();
+        self.recent.insert(0, fname.to_string());
+        self.recent.truncate(MAX_RECENT);
+        self.fit_view();
+        Ok(())
+    }
 
-        let centro = pos2(scrx, scry);
-        let radio = 1.0 * self.zoom;
-        let color = Color32::from_rgb(255, 255, 255);
+    fn load_builtin(&mut self, name: &str) {
+        self.mesh = Mesh::builtin(name).unwrap_or_default();
+        self.model_path.clear();
+        self.error = None;
+        self.fit_view();
+    }
 
-        painter.circle_filled(centro, radio, color);
+    // Fotogramas y paso para grabar una vuelta a la velocidad y en el
+    // sentido del giro actual (hacia atrás el paso es negativo)
+    fn recording_turn(&self) -> (usize, f32) {
+        let dir = if self.reverse { -1.0 } else { 1.0 };
+        let fps = AnimOptions::default().fps;
+        let step = (self.angle_step > 0.0).then_some(dir * self.angle_step / fps);
+        AnimOptions::turn(None, step)
     }
 
-    fn draw_point(p: Point2D, zoom: f32, painter: &egui::Painter) {
-        // También puedes obtener los límites
-        // let min = painter.clip_rect().min; // Esquina superior izquierda (Pos2)
-        // let max = painter.clip_rect().max; // Esquina inferior derecha (Pos2)
+    // Centra el modelo en su centroide, lo escala y aleja la cámara lo
+    // justo para que llene la vista, tenga el tamaño que tenga. Esa
+    // distancia es la del zoom 1, así que nunca queda fuera de su rango
+    fn fit_view(&mut self) {
+        (self.center, self.scale) = self.mesh.fit(camera::FIT_RADIUS);
+        self.camera.translate(-self.camera.target);
+        self.fit_distance = self
+            .camera
+            .fit_distance(camera::FIT_RADIUS, camera::aspect(self.view_rect));
+        self.set_zoom(1.0);
+    }
 
-        let centro = pos2(p.x, p.y);
-        // let radio = zoom.min(3.5);
-        let radio = (zoom + 0.25) / 3.0;
-        // let color = Color32::from_rgb(255, 255, 255);
-        let color = Color32::LIGHT_RED;
+    // En perspectiva el zoom acerca la cámara al modelo: a más zoom, menos
+    // distancia. En paralela la cámara no se mueve y el zoom es la escala,
+    // con el mismo tamaño aparente que tendría en perspectiva
+    fn set_zoom(&mut self, zoom: f32) {
+        self.zoom = zoom;
+        let distance = self.fit_distance / zoom;
+        match self.camera.projection {
+            Projection::Perspective => self.camera.set_distance(distance),
+            Projection::Orthographic => {
+                self.camera.ortho_height = self.camera.visible_height(distance).max(1e-3);
+            }
+        }
+    }
 
-        painter.circle_filled(centro, radio, color);
+    // Al cambiar de proyección se conserva lo que se ve a la distancia del
+    // objetivo, aunque quede fuera del rango del zoom
+    fn set_projection(&mut self, projection: Projection) {
+        if projection == self.camera.projection {
+            return;
+        }
+        match projection {
+            Projection::Perspective => {
+                let distance = self.camera.ortho_height / self.camera.visible_height(1.0);
+                self.camera.set_distance(distance);
+            }
+            Projection::Orthographic => {
+                let height = self.camera.visible_height(self.camera.distance());
+                self.camera.ortho_height = height.max(1e-3);
+            }
+        }
+        self.camera.projection = projection;
     }
 
-    fn draw_lines(lines: &Vec<Pos2>, painter: &egui::Painter) {
-        let stroke = Stroke::new(0.5, egui::Color32::LIGHT_YELLOW);
-        painter.line(lines.to_vec(), stroke);
+    // Vista normalizada del modelo quieto, tal como está en el fichero
+    fn apply_preset(&mut self, preset: ViewPreset) {
+        self.angles = [0.0; 3];
+        self.playing = false;
+        self.camera.set_preset(preset);
     }
 
-    pub fn draw_object3D(&self, painter: &egui::Painter) {
-        let dz = MAX_ZOOM - self.zoom;
-        let worldr: Rect = Rect::from_min_max(pos2(-1.0, -1.0), pos2(1.0, 1.0));
-        let screenr: Rect = painter.clip_rect();
-        static mut ANGLE: f32 = 0.0;
-        unsafe {
-            ANGLE = (ANGLE + self.angle_step) % 360.0;
-            // if ANGLE > 360.0 {
-            //     ANGLE = 0.0;
-            // }
-        }
-
-        // Draw points@vertices
-        if self.draw_vs {
-            for v in crate::penger::VS {
-                let mut a = *v;
-                a.y = -1.0 * a.y;
-
-                unsafe {
-                    if self.rotx {
-                        a = a.rotate(ANGLE, Axe::X);
+    // Ratón sobre el área de dibujo: arrastrar con el botón izquierdo gira
+    // la cámara (arcball) y con el central o el derecho la desplaza; la
+    // rueda y el pellizco acercan y el doble clic centra la vista en el
+    // punto del modelo bajo el cursor
+    fn handle_mouse(&mut self, ui: &egui::Ui, response: &egui::Response, dt: f32) {
+        let rect = response.rect;
+        if response.dragged_by(egui::PointerButton::Primary) {
+            let arc = response
+                .interact_pointer_pos()
+                .and_then(|to| self.camera.arcball(rect, to - response.drag_delta(), to));
+            self.spin = arc.map(|(axis, angle)| {
+                self.camera.orbit(axis, -angle);
+                (axis, angle / dt.max(1e-3))
+            });
+        } else if response.dragged_by(egui::PointerButton::Middle)
+            || response.dragged_by(egui::PointerButton::Secondary)
+        {
+            let d = response.drag_delta() * self.camera.pixel_size(rect);
+            let (right, up, _) = self.camera.basis();
+            self.camera.translate(up * d.y - right * d.x);
+            self.spin = None;
+        } else if let Some((axis, speed)) = self.spin {
+            self.camera.orbit(axis, -speed * dt);
+            let speed = speed * (-SPIN_DAMPING * dt).exp();
+            self.spin = (speed.abs() > MIN_SPIN).then_some((axis, speed));
+        }
+
+        if response.hovered() {
+            let (scroll, pinch) = ui.input(|i| (i.smooth_scroll_delta.y, i.zoom_delta()));
+            if scroll != 0.0 || pinch != 1.0 {
+                let zoom = self.zoom * pinch * (scroll * SCROLL_ZOOM).exp();
+                self.set_zoom(zoom.clamp(camera::MIN_ZOOM, camera::MAX_ZOOM));
+            }
+        }
+
+        if response.double_clicked()
+            && let Some(pos) = response.interact_pointer_pos()
+            && let Some((origin, dir)) = self.camera.ray(rect, pos)
+        {
+            let tolerance = PICK_PIXELS * self.camera.pixel_size(rect);
+            if let Some(hit) = render::pick(&self.mesh, &self.view(), origin, dir, tolerance) {
+                self.camera.translate(hit - self.camera.target);
+            }
+        }
+    }
+
+    // Panel lateral con los parámetros de la cámara
+    fn camera_panel(&mut self, ui: &mut egui::Ui) {
+        ui.heading("Camera");
+        ui.separator();
+        let before = self.camera;
+        egui::Grid::new("camera_grid")
+            .num_columns(2)
+            .spacing([8.0, 6.0])
+            .show(ui, |ui| {
+                ui.label("Position:");
+                vec3_edit(ui, &mut self.camera.position);
+                ui.end_row();
+                ui.label("Target:");
+                vec3_edit(ui, &mut self.camera.target);
+                ui.end_row();
+                ui.label("Up:");
+                vec3_edit(ui, &mut self.camera.up);
+                ui.end_row();
+                ui.label("FOV:");
+                ui.add_enabled(
+                    self.camera.projection == Projection::Perspective,
+                    egui::Slider::new(
+                        &mut self.camera.fov_y,
+                        camera::MIN_FOV_Y..=camera::MAX_FOV_Y,
+                    )
+                    .suffix("°"),
+                );
+                ui.end_row();
+                ui.label("Near:");
+                let far = self.camera.far;
+                ui.add(
+                    egui::DragValue::new(&mut self.camera.near)
+                        .speed(0.01)
+                        .range(camera::MIN_NEAR..=far / camera::MIN_DEPTH_RATIO),
+                );
+                ui.end_row();
+                ui.label("Far:");
+                let near = self.camera.near;
+                ui.add(
+                    egui::DragValue::new(&mut self.camera.far)
+                        .speed(1.0)
+                        .range(near * camera::MIN_DEPTH_RATIO..=f32::MAX),
+                );
+                ui.end_row();
+            });
+        // Un "arriba" nulo o paralelo a la mirada, o la cámara sobre el
+        // objetivo, dejan la vista sin base: se descarta la edición
+        if !self.camera.is_oriented() {
+            self.camera.position = before.position;
+            self.camera.target = before.target;
+            self.camera.up = before.up;
+        }
+        ui.separator();
+        if ui.button("Reset Camera").clicked() {
+            self.camera = Camera {
+                projection: self.camera.projection,
+                ..Camera::default()
+            };
+            self.set_zoom(self.zoom);
+        }
+        // Mover la cámara a mano también cambia el zoom en perspectiva
+        if self.camera.projection == Projection::Perspective && self.camera != before {
+            self.zoom = (self.fit_distance / self.camera.distance())
+                .clamp(camera::MIN_ZOOM, camera::MAX_ZOOM);
+        }
+
+        ui.add_space(12.0);
+        ui.heading("Light");
+        ui.separator();
+        egui::Grid::new("light_grid")
+            .num_columns(2)
+            .spacing([8.0, 6.0])
+            .show(ui, |ui| {
+                ui.label("Direction:");
+                vec3_edit(ui, &mut self.light.direction);
+                ui.end_row();
+                ui.label("Diffuse:");
+                ui.add(egui::Slider::new(&mut self.light.diffuse, 0.0..=1.0));
+                ui.end_row();
+                ui.label("Ambient:");
+                ui.add(egui::Slider::new(&mut self.light.ambient, 0.0..=1.0));
+                ui.end_row();
+            });
+        if ui.button("Reset Light").clicked() {
+            self.light = Light::default();
+        }
+    }
+
+    // Vuelve a los valores de vista iniciales conservando la malla cargada
+    fn restart_view(&mut self) {
+        let mesh = std::mem::take(&mut self.mesh);
+        let model_path = std::mem::take(&mut self.model_path);
+        let recent = std::mem::take(&mut self.recent);
+        *self = Self {
+            mesh,
+            model_path,
+            recent,
+            background: self.background,
+            view_rect: self.view_rect,
+            ..Self::new()
+        };
+        self.fit_view();
+    }
+
+    // Diálogo nativo para elegir el modelo a abrir
+    #[cfg(not(target_arch = "wasm32"))]
+    fn open_dialog(&mut self) {
+        let picked = rfd::FileDialog::new()
+            .add_filter("3D models", &["obj", "stl", "ply", "off", "gltf", "glb"])
+            .add_filter("All files", &["*"])
+            .pick_file();
+        if let Some(path) = picked {
+            let fname = path.to_string_lossy().into_owned();
+            self.error = self.load_model(&fname).err().map(|e| e.to_string());
+        }
+    }
+
+    // Carga el primer fichero soltado sobre la ventana; mientras se
+    // arrastra se indica en pantalla
+    fn handle_dropped_files(&mut self, ctx: &egui::Context) {
+        let (hovering, dropped) = ctx.input(|i| {
+            let dropped = i.raw.dropped_files.iter().find_map(|f| f.path.clone());
+            (!i.raw.hovered_files.is_empty(), dropped)
+        });
+        if let Some(path) = dropped {
+            let fname = path.to_string_lossy().into_owned();
+            self.error = self.load_model(&fname).err().map(|e| e.to_string());
+        }
+        if hovering {
+            let painter = ctx.layer_painter(egui::LayerId::new(
+                egui::Order::Foreground,
+                egui::Id::new("drop_target"),
+            ));
+            let screen = ctx.content_rect();
+            painter.rect_filled(screen, 0.0, Color32::from_black_alpha(192));
+            painter.text(
+                screen.center(),
+                egui::Align2::CENTER_CENTER,
+                "Drop a model to open it",
+                egui::FontId::proportional(24.0),
+                Color32::WHITE,
+            );
+        }
+    }
+
+    // Menú "File": abrir, recientes, guardar y salir
+    fn file_menu(&mut self, ui: &mut egui::Ui) {
+        ui.menu_button("File", |ui| {
+            #[cfg(not(target_arch = "wasm32"))]
+            if ui.button("Open…").clicked() {
+                self.open_dialog();
+            }
+            ui.menu_button("Recent", |ui| {
+                if self.recent.is_empty() {
+                    ui.label("No recent files");
+                }
+                let mut picked = None;
+                for fname in &self.recent {
+                    if ui.button(fname).clicked() {
+                        picked = Some(fname.clone());
                     }
-                    if self.roty {
-                        a = a.rotate(ANGLE, Axe::Y);
+                }
+                if let Some(fname) = picked {
+                    self.error = self.load_model(&fname).err().map(|e| e.to_string());
+                }
+                if !self.recent.is_empty() {
+                    ui.separator();
+                    if ui.button("Clear").clicked() {
+                        self.recent.clear();
                     }
-                    if self.rotz {
-                        a = a.rotate(ANGLE, Axe::Z);
+                }
+            });
+            ui.menu_button("Built-in", |ui| {
+                for &(name, _, _) in crate::models::MODELS {
+                    if ui.button(name).clicked() {
+                        self.load_builtin(name);
                     }
                 }
-                let p2d = a.convert_to_2D(dz, &worldr, &screenr);
-                App3D::draw_point(p2d, self.zoom, painter);
+            });
+            if ui.button("Save As…").clicked() {
+                self.save_dialog = Some(SaveDialog {
+                    path: String::new(),
+                    opts: SaveOptions::default(),
+                });
             }
+            if ui.button("Export SVG…").clicked() {
+                self.svg_dialog = Some(SvgDialog {
+                    path: String::new(),
+                    opts: SvgOptions::default(),
+                });
+            }
+            if ui.button("Record Animation…").clicked() {
+                // Por defecto, una vuelta completa a la velocidad actual y
+                // al tamaño del área de dibujo
+                let d = AnimOptions::default();
+                let (frames, step) = self.recording_turn();
+                let size = self.view_rect.size();
+                self.anim_dialog = Some(AnimDialog {
+                    path: String::new(),
+                    opts: AnimOptions {
+                        width: if size.x >= 1.0 {
+                            size.x as usize
+                        } else {
+                            d.width
+                        },
+                        height: if size.y >= 1.0 {
+                            size.y as usize
+                        } else {
+                            d.height
+                        },
+                        frames,
+                        step,
+                        background: self.background,
+                        ..d
+                    },
+                });
+            }
+            if !cfg!(target_arch = "wasm32") {
+                ui.separator();
+                if ui.button("Quit").clicked() {
+                    ui.ctx().send_viewport_cmd(egui::ViewportCommand::Close);
+                }
+            }
+        });
+    }
+
+    // Guarda la malla actual; el formato lo decide la extensión
+    pub fn save_model(&self, fname: &str, opts: &SaveOptions) -> std::io::Result<()> {
+        files::save_mesh(fname, &self.mesh, opts)
+    }
+
+    // Panel con el último error de carga/guardado; se cierra con "Dismiss"
+    fn show_error(&mut self, ctx: &egui::Context) {
+        let Some(err) = &self.error else {
+            return;
+        };
+        let mut dismiss = false;
+        egui::TopBottomPanel::top("error").show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                ui.colored_label(egui::Color32::RED, "Error: ");
+                ui.label(err);
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    dismiss = ui.button("Dismiss").clicked();
+                });
+            });
+        });
+        if dismiss {
+            self.error = None;
         }
+    }
 
-        // Draw Lines between vertices
-        if self.draw_fs {
-            let mut lines: Vec<Pos2> = vec![];
-            for f in crate::penger::FS {
-                for i in 0..f.len() {
-                    let mut a = crate::penger::VS[f[i] as usize];
-                    let mut b = crate::penger::VS[f[(i + 1) % f.len()] as usize];
-                    a.y = -1.0 * a.y; // Invert Y-coordinate top-down
-                    b.y = -1.0 * b.y; // Invert Y-coordinate top-down
-
-                    unsafe {
-                        if self.rotx {
-                            a = a.rotate(ANGLE, Axe::X);
-                            b = b.rotate(ANGLE, Axe::X);
-                        }
-                        if self.roty {
-                            a = a.rotate(ANGLE, Axe::Y);
-                            b = b.rotate(ANGLE, Axe::Y);
+    // Ventana "Save As…": ruta y opciones del formato elegido
+    fn show_save_dialog(&mut self, ctx: &egui::Context) {
+        let Some(dialog) = &mut self.save_dialog else {
+            return;
+        };
+        let (mut save, mut cancel) = (false, false);
+        egui::Window::new("Save As…")
+            .collapsible(false)
+            .resizable(false)
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("File: ");
+                    ui.add(
+                        egui::TextEdit::singleline(&mut dialog.path)
+                            .hint_text("path/to/model.{obj,stl,ply}")
+                            .desired_width(240.0),
+                    );
+                });
+                let ext = std::path::Path::new(&dialog.path)
+                    .extension()
+                    .map(|e| e.to_string_lossy().to_lowercase());
+                let opts = &mut dialog.opts;
+                match ext.as_deref() {
+                    Some("obj") => {
+                        ui.horizontal(|ui| {
+                            ui.checkbox(&mut opts.normals, "Normals");
+                            ui.checkbox(&mut opts.uvs, "UVs");
+                            ui.checkbox(&mut opts.groups, "Groups");
+                        });
+                    }
+                    Some("stl") => {
+                        ui.checkbox(&mut opts.stl_ascii, "ASCII");
+                    }
+                    Some("ply") => {
+                        ui.horizontal(|ui| {
+                            let enc = &mut opts.ply_encoding;
+                            ui.radio_value(enc, PlyEncoding::Ascii, "ASCII");
+                            ui.radio_value(enc, PlyEncoding::BinaryLittleEndian, "Binary LE");
+                            ui.radio_value(enc, PlyEncoding::BinaryBigEndian, "Binary BE");
+                        });
+                    }
+                    _ => {
+                        ui.colored_label(Color32::LIGHT_RED, "Use .obj, .stl or .ply");
+                    }
+                }
+                ui.horizontal(|ui| {
+                    save = ui.button("Save").clicked();
+                    cancel = ui.button("Cancel").clicked();
+                });
+            });
+
+        if save {
+            let fname = dialog.path.clone();
+            let opts = dialog.opts;
+            self.error = self
+                .save_model(&fname, &opts)
+                .err()
+                .map(|e| format!("{fname}: {e}"));
+        }
+        if save || cancel {
+            self.save_dialog = None;
+        }
+    }
+
+    // Ventana "Export SVG…": ruta, trazo y colores del dibujo
+    fn show_svg_dialog(&mut self, ctx: &egui::Context) {
+        let Some(dialog) = &mut self.svg_dialog else {
+            return;
+        };
+        let (mut export, mut cancel) = (false, false);
+        egui::Window::new("Export SVG…")
+            .collapsible(false)
+            .resizable(false)
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("File: ");
+                    ui.add(
+                        egui::TextEdit::singleline(&mut dialog.path)
+                            .hint_text("path/to/drawing.svg")
+                            .desired_width(240.0),
+                    );
+                });
+                let opts = &mut dialog.opts;
+                ui.checkbox(&mut opts.hidden_lines, "Remove hidden lines");
+                ui.horizontal(|ui| {
+                    ui.label("Line width: ");
+                    ui.add(
+                        egui::DragValue::new(&mut opts.line_width)
+                            .speed(0.1)
+                            .range(0.1..=10.0),
+                    );
+                    optional_color(ui, &mut opts.line_color, "Material colours");
+                });
+                ui.horizontal(|ui| {
+                    ui.label("Point radius: ");
+                    ui.add(
+                        egui::DragValue::new(&mut opts.point_radius)
+                            .speed(0.1)
+                            .range(0.1..=10.0),
+                    );
+                    optional_color(ui, &mut opts.point_color, "Vertex colours");
+                });
+                ui.horizontal(|ui| {
+                    ui.label("Background: ");
+                    optional_color(ui, &mut opts.background, "Transparent");
+                });
+                ui.horizontal(|ui| {
+                    export = ui.button("Export").clicked();
+                    cancel = ui.button("Cancel").clicked();
+                });
+            });
+
+        if export {
+            let fname = dialog.path.clone();
+            let opts = dialog.opts;
+            self.error = svg::write_svg(&fname, &self.mesh, &self.view(), self.view_rect, &opts)
+                .err()
+                .map(|e| format!("{fname}: {e}"));
+        }
+        if export || cancel {
+            self.svg_dialog = None;
+        }
+    }
+
+    // Ventana "Record Animation…": fichero, tamaño y ritmo de la vuelta
+    fn show_anim_dialog(&mut self, ctx: &egui::Context) {
+        let Some(dialog) = &mut self.anim_dialog else {
+            return;
+        };
+        let (mut record, mut cancel) = (false, false);
+        egui::Window::new("Record Animation…")
+            .collapsible(false)
+            .resizable(false)
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("File: ");
+                    ui.add(
+                        egui::TextEdit::singleline(&mut dialog.path)
+                            .hint_text("path/to/spin.{gif,png}")
+                            .desired_width(240.0),
+                    );
+                });
+                let opts = &mut dialog.opts;
+                ui.horizontal(|ui| {
+                    ui.label("Size: ");
+                    ui.add(egui::DragValue::new(&mut opts.width).range(1..=4096));
+                    ui.label("x");
+                    ui.add(egui::DragValue::new(&mut opts.height).range(1..=4096));
+                });
+                ui.horizontal(|ui| {
+                    ui.label("Frames: ");
+                    ui.add(egui::DragValue::new(&mut opts.frames).range(1..=anim::MAX_FRAMES));
+                    ui.label("Step: ");
+                    let step = ui.add(
+                        egui::DragValue::new(&mut opts.step)
+                            .speed(0.1)
+                            .range(-anim::MAX_STEP..=anim::MAX_STEP)
+                            .suffix("°"),
+                    );
+                    if step.changed() {
+                        opts.step = anim::clamp_step(opts.step);
+                    }
+                    if ui.button("Full turn").clicked() {
+                        (opts.frames, opts.step) = AnimOptions::turn(None, Some(opts.step));
+                    }
+                });
+                let is_gif = dialog.path.to_lowercase().ends_with(".gif");
+                ui.add_enabled_ui(is_gif, |ui| {
+                    ui.horizontal(|ui| {
+                        ui.label("FPS: ");
+                        ui.add(
+                            egui::DragValue::new(&mut opts.fps)
+                                .speed(0.1)
+                                .range(1.0..=100.0),
+                        );
+                        let mut forever = opts.loops.is_none();
+                        if ui.checkbox(&mut forever, "Loop forever").changed() {
+                            opts.loops = (!forever).then_some(1);
                         }
-                        if self.rotz {
-                            a = a.rotate(ANGLE, Axe::Z);
-                            b = b.rotate(ANGLE, Axe::Z);
+                        if let Some(n) = &mut opts.loops {
+                            ui.label("Repeat: ");
+                            ui.add(egui::DragValue::new(n));
                         }
-                    }
-                    // let p1 = App3D::world2screen(a.translate_z(dz).project(), painter);
-                    // let p2 = App3D::world2screen(b.translate_z(dz).project(), painter);
+                    });
+                });
+                ui.horizontal(|ui| {
+                    record = ui.button("Record").clicked();
+                    cancel = ui.button("Cancel").clicked();
+                });
+            });
+
+        if record {
+            let fname = dialog.path.clone();
+            let opts = dialog.opts;
+            let axes = self.spin_axes();
+            self.error = anim::record(&fname, &self.mesh, &self.view(), &axes, &opts)
+                .err()
+                .map(|e| format!("{fname}: {e}"));
+        }
+        if record || cancel {
+            self.anim_dialog = None;
+        }
+    }
+
+    // Ejes marcados para girar
+    fn spin_axes(&self) -> Vec<Axe> {
+        [
+            (self.rotx, Axe::X),
+            (self.roty, Axe::Y),
+            (self.rotz, Axe::Z),
+        ]
+        .into_iter()
+        .filter(|&(on, _)| on)
+        .map(|(_, axe)| axe)
+        .collect()
+    }
 
-                    // let p1 = a.translate_z(dz).project().world2screen(worldr, screenr);
-                    // let p2 = b.translate_z(dz).project().world2screen(worldr, screenr);
+    // Avanza el giro de los ejes marcados lo que corresponde a `dt`
+    // segundos, al margen de cuántos fotogramas se pinten
+    fn advance(&mut self, dt: f32) {
+        let dir = if self.reverse { -1.0 } else { 1.0 };
+        let spin = [self.rotx, self.roty, self.rotz];
+        for (angle, on) in self.angles.iter_mut().zip(spin) {
+            if on {
+                *angle = (*angle + dir * self.angle_step * dt).rem_euclid(360.0);
+            }
+        }
+    }
 
-                    let p1 = a.convert_to_2D(dz, &worldr, &screenr);
-                    let p2 = b.convert_to_2D(dz, &worldr, &screenr);
+    // Vista actual para el generador de primitivas. Un eje desmarcado
+    // conserva el ángulo al que llegó
+    fn view(&self) -> View {
+        let rotation = [Axe::X, Axe::Y, Axe::Z]
+            .into_iter()
+            .zip(self.angles)
+            .collect();
+        View {
+            center: self.center,
+            scale: self.scale,
+            rotation,
+            camera: self.camera,
+            draw_vs: self.draw_vs,
+            draw_fs: self.draw_fs,
+            draw_bbox: self.draw_bbox,
+            solid: self.solid,
+            light: self.light,
+            line_width: 0.5,
+            point_radius: POINT_RADIUS,
+        }
+    }
 
-                    let p1: Pos2 = pos2(p1.x, p1.y);
-                    let p2: Pos2 = pos2(p2.x, p2.y);
-                    lines.push(p1);
-                    lines.push(p2);
+    // Pinta la lista de primitivas con egui, en su orden. Los triángulos
+    // seguidos van juntos en una misma malla de egui: sin suavizado de
+    // bordes, así que no se ven costuras entre caras vecinas
+    fn paint(list: &RenderList, painter: &egui::Painter) {
+        let pos = |p: &ScreenPoint| pos2(p.pos.x, p.pos.y);
+        let mut shapes = vec![];
+        let mut mesh = egui::Mesh::default();
+        for item in &list.items {
+            if !matches!(item, Primitive::Triangle { .. }) && !mesh.is_empty() {
+                shapes.push(Shape::mesh(std::mem::take(&mut mesh)));
+            }
+            match item {
+                Primitive::Triangle { vs, color } => {
+                    let base = mesh.vertices.len() as u32;
+                    for p in vs {
+                        mesh.colored_vertex(pos(p), *color);
+                    }
+                    mesh.add_triangle(base, base + 1, base + 2);
+                }
+                Primitive::Line { a, b, width, color } => {
+                    shapes.push(Shape::line_segment(
+                        [pos(a), pos(b)],
+                        Stroke::new(*width, *color),
+                    ));
+                }
+                Primitive::Point { p, radius, color } => {
+                    shapes.push(Shape::circle_filled(pos(p), *radius, *color));
                 }
             }
-            App3D::draw_lines(&lines, painter);
         }
+        if !mesh.is_empty() {
+            shapes.push(Shape::mesh(mesh));
+        }
+        painter.extend(shapes);
+    }
+
+    #[allow(non_snake_case)]
+    pub fn draw_object3D(&self, painter: &egui::Painter) {
+        let list = render::build(&self.mesh, &self.view(), painter.clip_rect());
+        App3D::paint(&list, painter);
     }
 
     pub fn draw_contents(&self, painter: &egui::Painter) {
-        //self.draw_circle(painter);
         self.draw_object3D(painter);
     }
 }
 
 // -- Implementation eframe@App3D: ----------------------------------------
 impl eframe::App for App3D {
-    /// Called by the framework to save state before shutdown.
-    // fn save(&mut self, storage: &mut dyn eframe::Storage) {
-    //     eframe::set_value(storage, eframe::APP_KEY, self);
-    // }
+    // Called by the framework to save state before shutdown.
+    fn save(&mut self, storage: &mut dyn eframe::Storage) {
+        eframe::set_value(storage, RECENT_KEY, &self.recent);
+    }
 
     /// Called each time the UI needs repainting, which may be many times per second.
-    fn update(&mut self, ctx: &egui::Context, frame: &mut eframe::Frame) {
+    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        self.show_error(ctx);
+        self.show_save_dialog(ctx);
+        self.show_svg_dialog(ctx);
+        self.show_anim_dialog(ctx);
+        self.handle_dropped_files(ctx);
+
+        if self.show_camera {
+            egui::SidePanel::right("camera_panel")
+                .resizable(false)
+                .show(ctx, |ui| self.camera_panel(ui));
+        }
+
         egui::CentralPanel::default().show(ctx, |ui| {
             // Panel de controles en la parte superior
             ui.vertical(|ui| {
                 ui.horizontal(|ui| {
+                    self.file_menu(ui);
+                    ui.separator();
                     ui.colored_label(egui::Color32::RED, "·:Penger 3D:·");
 
                     ui.colored_label(egui::Color32::LIGHT_BLUE, "Theme: ");
                     egui::widgets::global_theme_preference_buttons(ui);
 
-                    let is_web = cfg!(target_arch = "wasm32");
-                    if !is_web {
-                        //ui.menu_button("File", |ui| {
-                        if ui.button("Quit").clicked() {
-                            ctx.send_viewport_cmd(egui::ViewportCommand::Close);
-                        }
-                        //});
-                        ui.add_space(16.0);
+                    ui.separator();
+                    ui.colored_label(egui::Color32::LIGHT_BLUE, "Model: ");
+                    let path_edit = ui.add(
+                        egui::TextEdit::singleline(&mut self.model_path)
+                            .hint_text("path/to/model.{obj,stl,ply,off,gltf,glb}")
+                            .desired_width(240.0),
+                    );
+                    let enter =
+                        path_edit.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+                    if ui.button("Load").clicked() || enter {
+                        let fname = self.model_path.clone();
+                        self.error = self.load_model(&fname).err().map(|e| e.to_string());
                     }
                 });
 
@@ -209,31 +910,73 @@ impl eframe::App for App3D {
                     ui.checkbox(&mut self.rotz, "Rotate Z");
                     ui.separator();
 
+                    let play = if self.playing {
+                        "⏸ Pause"
+                    } else {
+                        "▶ Play"
+                    };
+                    if ui.button(play).clicked() {
+                        self.playing = !self.playing;
+                    }
+                    if ui
+                        .add_enabled(!self.playing, egui::Button::new("Step"))
+                        .clicked()
+                    {
+                        self.advance(STEP_DT);
+                    }
+                    ui.checkbox(&mut self.reverse, "Reverse");
+                    ui.separator();
+
                     ui.horizontal(|ui| {
                         ui.checkbox(&mut self.draw_vs, "Vertices");
                         ui.checkbox(&mut self.draw_fs, "Faces");
+                        ui.checkbox(&mut self.solid, "Solid");
+                        ui.checkbox(&mut self.draw_bbox, "BBox");
                     });
 
                     ui.separator();
-                    ui.colored_label(egui::Color32::LIGHT_YELLOW, "Angle Step: ");
+                    ui.colored_label(egui::Color32::LIGHT_YELLOW, "Speed: ");
                     ui.add(
                         egui::DragValue::new(&mut self.angle_step)
-                            .speed(0.1)
-                            .range(MIN_ANGLE_STEP..=MAX_ANGLE_STEP),
+                            .speed(1.0)
+                            .range(render::MIN_ANGLE_STEP..=render::MAX_ANGLE_STEP)
+                            .suffix("°/s"),
                     );
 
                     ui.separator();
                     ui.colored_label(egui::Color32::LIGHT_YELLOW, "Zoom: ");
-                    ui.add(
-                        egui::DragValue::new(&mut self.zoom)
+                    let mut zoom = self.zoom;
+                    let zoom_edit = ui.add(
+                        egui::DragValue::new(&mut zoom)
                             .speed(0.1)
-                            .range(MIN_ZOOM..=MAX_ZOOM),
+                            .range(camera::MIN_ZOOM..=camera::MAX_ZOOM),
                     );
+                    if zoom_edit.changed() {
+                        self.set_zoom(zoom);
+                    }
+                    ui.toggle_value(&mut self.show_camera, "Camera");
+                    let mut ortho = self.camera.projection == Projection::Orthographic;
+                    if ui.toggle_value(&mut ortho, "Ortho").changed() {
+                        self.set_projection(if ortho {
+                            Projection::Orthographic
+                        } else {
+                            Projection::Perspective
+                        });
+                    }
+                    ui.menu_button("Views", |ui| {
+                        for preset in ViewPreset::ALL {
+                            if ui.button(preset.label()).clicked() {
+                                self.apply_preset(preset);
+                            }
+                        }
+                    });
                     ui.separator();
 
+                    if ui.button("Fit").clicked() {
+                        self.fit_view();
+                    }
                     if ui.button("Restart View").clicked() {
-                        //self.calculate_bounds_and_fit(ui.available_rect_before_wrap());
-                        *self = Self::new();
+                        self.restart_view();
                     }
                 });
 
@@ -246,13 +989,20 @@ impl eframe::App for App3D {
             let mut painter = ui.painter_at(available_rect_before_wrap);
 
             // Dibujar un fondo para el área del mapa
-            painter.rect_filled(
-                available_rect_before_wrap,
-                0.0,
-                egui::Color32::from_rgb(50, 50, 50),
-            );
+            painter.rect_filled(available_rect_before_wrap, 0.0, self.background);
             let screenr: Rect = painter.clip_rect();
             painter.set_clip_rect(screenr);
+            self.view_rect = screenr;
+            let dt = ctx.input(|i| i.stable_dt);
+            let response = ui.interact(
+                screenr,
+                ui.id().with("view3d"),
+                egui::Sense::click_and_drag(),
+            );
+            self.handle_mouse(ui, &response, dt);
+            if self.playing {
+                self.advance(dt);
+            }
 
             self.draw_contents(&painter);
 
@@ -269,6 +1019,27 @@ impl eframe::App for App3D {
 }
 
 // -- Free functions: -----------------------------------------------------
+// Casilla para usar los colores propios del modelo o, si se desmarca, uno
+// elegido por el usuario
+fn optional_color(ui: &mut egui::Ui, color: &mut Option<Color32>, auto: &str) {
+    let mut use_auto = color.is_none();
+    if ui.checkbox(&mut use_auto, auto).changed() {
+        *color = (!use_auto).then_some(Color32::BLACK);
+    }
+    if let Some(c) = color {
+        ui.color_edit_button_srgba(c);
+    }
+}
+
+// Las tres coordenadas de un vector, en línea
+fn vec3_edit(ui: &mut egui::Ui, v: &mut Vec3) {
+    ui.horizontal(|ui| {
+        for (c, label) in [(&mut v.x, "x: "), (&mut v.y, "y: "), (&mut v.z, "z: ")] {
+            ui.add(egui::DragValue::new(c).speed(0.05).prefix(label));
+        }
+    });
+}
+
 fn powered_by_egui_and_eframe(ui: &mut egui::Ui) {
     ui.horizontal(|ui| {
         ui.spacing_mut().item_spacing.x = 0.0;
@@ -282,3 +1053,56 @@ fn powered_by_egui_and_eframe(ui: &mut egui::Ui) {
         ui.label(".");
     });
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reversed_view_records_a_negative_step() {
+        let mut app = App3D::new();
+        app.angle_step = 60.0;
+        assert_eq!(app.recording_turn(), (72, 5.0));
+        app.reverse = true;
+        assert_eq!(app.recording_turn(), (72, -5.0));
+        // Parado, se graba la vuelta por defecto
+        app.angle_step = 0.0;
+        assert_eq!(app.recording_turn(), AnimOptions::turn(None, None));
+
+        // "Full turn" en el diálogo tampoco cambia el sentido
+        assert_eq!(AnimOptions::turn(None, Some(-5.0)), (72, -5.0));
+    }
+
+    #[test]
+    fn fit_is_not_limited_by_the_zoom_range() {
+        let mut app = App3D::new();
+        app.view_rect = Rect::from_min_size(egui::pos2(0.0, 0.0), egui::vec2(300.0, 600.0));
+        app.camera.fov_y = 5.0;
+        app.fit_view();
+        // Hace falta alejarse más que con el campo de visión por defecto,
+        // pero la vista encajada es siempre el zoom 1
+        let distance = app.camera.distance();
+        assert!(distance > 2.0 * Camera::default().distance());
+        assert_eq!(app.zoom, 1.0);
+        // Volver a aplicar el zoom (p.e. al editarlo) no mueve la cámara
+        app.set_zoom(app.zoom);
+        assert!((app.camera.distance() - distance).abs() < 1e-3);
+        app.set_zoom(2.0);
+        assert!((app.camera.distance() - distance / 2.0).abs() < 1e-3);
+        app.set_zoom(1.0);
+
+        // El borde de la esfera encajada queda cerca del lado de la vista,
+        // también al pasar a la proyección paralela y volver
+        let aspect = camera::aspect(app.view_rect);
+        for projection in [
+            Projection::Perspective,
+            Projection::Orthographic,
+            Projection::Perspective,
+        ] {
+            app.set_projection(projection);
+            let vp = app.camera.projection_matrix(aspect) * app.camera.view();
+            let edge = (vp * Vec3::new(camera::FIT_RADIUS, 0.0, 0.0).extend(1.0)).project();
+            assert!(edge.x > 0.8 && edge.x < 1.0, "{projection:?}: {}", edge.x);
+        }
+    }
+}
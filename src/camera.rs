@@ -0,0 +1,446 @@
+// Copyright (C) 2026  Antonio-Miguel Corbi Bellot
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+// Cámara: desde dónde se mira, hacia dónde, con qué campo de visión y con
+// qué proyección
+
+use egui::{Pos2, Rect, pos2};
+
+use crate::math::{Mat4, Vec3};
+use crate::types::Point2D;
+
+// Campo de visión vertical por omisión, en grados
+pub const DEFAULT_FOV_Y: f32 = 22.5;
+pub const MIN_FOV_Y: f32 = 1.0;
+pub const MAX_FOV_Y: f32 = 150.0;
+
+// Zoom respecto a la distancia que encaja el modelo en la vista
+pub const MIN_ZOOM: f32 = 0.25;
+pub const MAX_ZOOM: f32 = 15.00;
+
+// Radio que ocupa el modelo tras encajarlo en la vista (el del Penger)
+pub const FIT_RADIUS: f32 = 0.75;
+
+// Holgura que se deja alrededor del modelo al encajarlo en la vista
+const FIT_MARGIN: f32 = 1.1;
+
+// Distancia mínima al objetivo: con la cámara encima no hay dirección de
+// mirada
+const MIN_DISTANCE: f32 = 1e-3;
+
+// Plano cercano más próximo admitido y proporción mínima entre el lejano y
+// el cercano: con los dos planos juntos la profundidad se indetermina
+pub const MIN_NEAR: f32 = 1e-3;
+pub const MIN_DEPTH_RATIO: f32 = 1.001;
+
+// Seno del ángulo mínimo entre el "arriba" y la dirección de mirada
+const MIN_UP_SIN: f32 = 1e-3;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Projection {
+    #[default]
+    Perspective,
+    // Paralela: las medidas no dependen de la distancia
+    Orthographic,
+}
+
+// Vistas normalizadas: desde dónde mira la cámara al objetivo
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ViewPreset {
+    Front,
+    Back,
+    Left,
+    Right,
+    Top,
+    Bottom,
+    Isometric,
+    Dimetric,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Camera {
+    pub position: Vec3,
+    pub target: Vec3,
+    // Dirección que queda hacia arriba en la pantalla
+    pub up: Vec3,
+    // Campo de visión vertical, en grados
+    pub fov_y: f32,
+    // Planos de recorte cercano y lejano (distancias a la cámara)
+    pub near: f32,
+    pub far: f32,
+    pub projection: Projection,
+    // Alto del volumen visible en la proyección paralela
+    pub ortho_height: f32,
+}
+
+impl Default for Camera {
+    fn default() -> Self {
+        Self {
+            position: Vec3::new(0.0, 0.0, 14.0),
+            target: Vec3::ZERO,
+            up: Vec3::Y,
+            fov_y: DEFAULT_FOV_Y,
+            near: 0.1,
+            far: 1000.0,
+            projection: Projection::Perspective,
+            ortho_height: 2.0,
+        }
+    }
+}
+
+impl Camera {
+    // Cámara mirando a `target` desde `distance`, en la dirección de
+    // siempre (desde +Z)
+    pub fn looking_at(target: Vec3, distance: f32) -> Self {
+        let mut camera = Self {
+            target,
+            ..Self::default()
+        };
+        camera.set_distance(distance);
+        camera
+    }
+
+    pub fn distance(&self) -> f32 {
+        (self.position - self.target).length()
+    }
+
+    // Hay dirección de mirada y un "arriba" que no es paralelo a ella; si no,
+    // la base de la vista no está definida
+    pub fn is_oriented(&self) -> bool {
+        let forward = self.target - self.position;
+        forward.length() >= MIN_DISTANCE
+            && forward.cross(self.up).length() > MIN_UP_SIN * forward.length() * self.up.length()
+    }
+
+    // Acerca o aleja la cámara del objetivo sin cambiar la dirección
+    pub fn set_distance(&mut self, distance: f32) {
+        let dir = self.position - self.target;
+        let dir = if dir.length() > 0.0 {
+            dir.normalize()
+        } else {
+            Vec3::Z
+        };
+        self.position = self.target + dir * distance.max(MIN_DISTANCE);
+    }
+
+    // Alto de lo que se ve a la distancia del objetivo en perspectiva: con
+    // ese alto la proyección paralela muestra el modelo del mismo tamaño
+    pub fn visible_height(&self, distance: f32) -> f32 {
+        2.0 * distance * (self.fov_y.to_radians() / 2.0).tan()
+    }
+
+    // Distancia a la que una esfera de ese radio centrada en el objetivo
+    // llena la vista: cabe en el más estrecho de los dos campos de visión
+    pub fn fit_distance(&self, radius: f32, aspect: f32) -> f32 {
+        let half_y = self.fov_y.to_radians() / 2.0;
+        let half_x = (half_y.tan() * aspect).atan();
+        radius * FIT_MARGIN / half_y.min(half_x).sin()
+    }
+
+    // Coloca la cámara en una vista normalizada, a la misma distancia del
+    // objetivo
+    pub fn set_preset(&mut self, preset: ViewPreset) {
+        let (dir, up) = preset.direction();
+        self.position = self.target + dir * self.distance().max(MIN_DISTANCE);
+        self.up = up;
+    }
+
+    // Ejes de la cámara en el mundo: derecha, arriba y hacia dónde mira
+    pub fn basis(&self) -> (Vec3, Vec3, Vec3) {
+        let forward = (self.target - self.position).normalize();
+        let right = forward.cross(self.up).normalize();
+        (right, right.cross(forward), forward)
+    }
+
+    // Dirección desde `p` hacia el ojo. En paralela los rayos de vista no
+    // salen de `position`: todos llevan la dirección de la mirada
+    pub fn eye_direction(&self, p: Vec3) -> Vec3 {
+        match self.projection {
+            Projection::Perspective => self.position - p,
+            Projection::Orthographic => -self.basis().2,
+        }
+    }
+
+    // Gira la cámara `angle` grados alrededor del objetivo
+    pub fn orbit(&mut self, axis: Vec3, angle: f32) {
+        self.position = self.position.rotate_around(angle, axis, self.target);
+        self.up = self.up.rotate_axis(angle, axis);
+    }
+
+    // Desplaza la cámara junto con su objetivo
+    pub fn translate(&mut self, delta: Vec3) {
+        self.position += delta;
+        self.target += delta;
+    }
+
+    // Lo que mide un píxel en el mundo a la distancia del objetivo
+    pub fn pixel_size(&self, viewport: Rect) -> f32 {
+        let height = match self.projection {
+            Projection::Perspective => self.visible_height(self.distance()),
+            Projection::Orthographic => self.ortho_height,
+        };
+        height / viewport.height().max(1.0)
+    }
+
+    // Giro de arcball al arrastrar de `from` a `to`: eje (en el mundo) y
+    // ángulo, en grados, que debe girar la escena para seguir al cursor
+    pub fn arcball(&self, viewport: Rect, from: Pos2, to: Pos2) -> Option<(Vec3, f32)> {
+        let radius = viewport.width().min(viewport.height()) / 2.0;
+        let center = viewport.center();
+        // Punto de la esfera bajo el cursor (Y hacia arriba, Z hacia quien
+        // mira); fuera de ella, el más cercano de su borde
+        let sphere = |p: Pos2| {
+            let (x, y) = ((p.x - center.x) / radius, (center.y - p.y) / radius);
+            let d = x * x + y * y;
+            if d <= 1.0 {
+                Vec3::new(x, y, (1.0 - d).sqrt())
+            } else {
+                Vec3::new(x, y, 0.0).normalize()
+            }
+        };
+        let (a, b) = (sphere(from), sphere(to));
+        let axis = a.cross(b);
+        if axis.length() < 1e-6 {
+            return None;
+        }
+        let angle = a.dot(b).clamp(-1.0, 1.0).acos().to_degrees();
+        let (right, up, forward) = self.basis();
+        let axis = right * axis.x + up * axis.y - forward * axis.z;
+        Some((axis.normalize(), angle))
+    }
+
+    // Rayo (origen en el plano cercano y dirección unitaria) que pasa por
+    // ese punto de la pantalla
+    pub fn ray(&self, viewport: Rect, pos: Pos2) -> Option<(Vec3, Vec3)> {
+        let inverse = (self.projection_matrix(aspect(viewport)) * self.view()).inverse()?;
+        let x = (pos.x - viewport.min.x) / viewport.width() * 2.0 - 1.0;
+        let y = (pos.y - viewport.min.y) / viewport.height() * 2.0 - 1.0;
+        let near = inverse.transform_point(Vec3::new(x, y, 0.0));
+        let far = inverse.transform_point(Vec3::new(x, y, 1.0));
+        Some((near, (far - near).normalize()))
+    }
+
+    pub fn view(&self) -> Mat4 {
+        Mat4::look_at(self.position, self.target, self.up)
+    }
+
+    pub fn projection_matrix(&self, aspect: f32) -> Mat4 {
+        match self.projection {
+            Projection::Perspective => Mat4::perspective(self.fov_y, aspect, self.near, self.far),
+            Projection::Orthographic => Mat4::orthographic(
+                self.ortho_height * aspect,
+                self.ortho_height,
+                self.near,
+                self.far,
+            ),
+        }
+    }
+}
+
+impl ViewPreset {
+    pub const ALL: [ViewPreset; 8] = [
+        ViewPreset::Front,
+        ViewPreset::Back,
+        ViewPreset::Left,
+        ViewPreset::Right,
+        ViewPreset::Top,
+        ViewPreset::Bottom,
+        ViewPreset::Isometric,
+        ViewPreset::Dimetric,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ViewPreset::Front => "Front",
+            ViewPreset::Back => "Back",
+            ViewPreset::Left => "Left",
+            ViewPreset::Right => "Right",
+            ViewPreset::Top => "Top",
+            ViewPreset::Bottom => "Bottom",
+            ViewPreset::Isometric => "Isometric",
+            ViewPreset::Dimetric => "Dimetric",
+        }
+    }
+
+    // Dirección del objetivo a la cámara y lo que queda arriba en pantalla.
+    // La isométrica mira por la diagonal del cubo (elevación de 35.26°) y
+    // la dimétrica, con la misma orientación, desde más arriba para que los
+    // ejes X y Z queden iguales y el Y a la mitad. Con elevación e, el eje
+    // Y mide cos(e) y los otros dos √(1 - cos²(e)/2); igualar el primero a
+    // la mitad de los segundos da cos²(e) = 2/9, unos 61.87°
+    fn direction(self) -> (Vec3, Vec3) {
+        let orbit = |azimuth: f32, elevation: f32| {
+            let (az, el) = (azimuth.to_radians(), elevation.to_radians());
+            Vec3::new(el.cos() * az.sin(), el.sin(), el.cos() * az.cos())
+        };
+        match self {
+            ViewPreset::Front => (Vec3::Z, Vec3::Y),
+            ViewPreset::Back => (-Vec3::Z, Vec3::Y),
+            ViewPreset::Left => (-Vec3::X, Vec3::Y),
+            ViewPreset::Right => (Vec3::X, Vec3::Y),
+            ViewPreset::Top => (Vec3::Y, -Vec3::Z),
+            ViewPreset::Bottom => (-Vec3::Y, Vec3::Z),
+            ViewPreset::Isometric => (orbit(45.0, 35.264), Vec3::Y),
+            ViewPreset::Dimetric => {
+                let elevation = (2.0f32.sqrt() / 3.0).acos().to_degrees();
+                (orbit(45.0, elevation), Vec3::Y)
+            }
+        }
+    }
+}
+
+// Relación de aspecto (ancho / alto) del área de dibujo
+pub fn aspect(viewport: Rect) -> f32 {
+    let (w, h) = (viewport.width(), viewport.height());
+    if w > 0.0 && h > 0.0 { w / h } else { 1.0 }
+}
+
+// Coordenadas normalizadas ([-1, 1], Y hacia abajo) a píxeles del área de
+// dibujo. La proyección ya corrige el aspecto, así que el modelo no se
+// deforma aunque el área no sea cuadrada
+pub fn to_viewport(ndc: Vec3, viewport: Rect) -> Point2D {
+    let ndcr = Rect::from_min_max(pos2(-1.0, -1.0), pos2(1.0, 1.0));
+    Point2D { x: ndc.x, y: ndc.y }.world2screen(ndcr, viewport)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use egui::vec2;
+
+    #[test]
+    fn projection_keeps_proportions() {
+        let camera = Camera::looking_at(Vec3::ZERO, 10.0);
+        let viewport = Rect::from_min_size(pos2(0.0, 0.0), vec2(400.0, 100.0));
+        let vp = camera.projection_matrix(aspect(viewport)) * camera.view();
+        let screen = |p: Vec3| to_viewport((vp * p.extend(1.0)).project(), viewport);
+        let (o, x, y) = (screen(Vec3::ZERO), screen(Vec3::X), screen(Vec3::Y));
+        // El origen al centro; una unidad en X mide en pantalla lo mismo que
+        // en Y, y +Y queda hacia arriba
+        assert!((o.x - 200.0).abs() < 1e-3 && (o.y - 50.0).abs() < 1e-3);
+        assert!(((x.x - o.x) - (o.y - y.y)).abs() < 1e-3);
+        assert!(x.x > o.x && y.y < o.y);
+    }
+
+    #[test]
+    fn distance_keeps_the_direction() {
+        let mut camera = Camera {
+            position: Vec3::new(3.0, 0.0, 4.0),
+            ..Camera::default()
+        };
+        assert_eq!(camera.distance(), 5.0);
+        camera.set_distance(10.0);
+        assert!((camera.position - Vec3::new(6.0, 0.0, 8.0)).length() < 1e-5);
+    }
+
+    #[test]
+    fn degenerate_cameras_are_not_oriented() {
+        let camera = Camera::default();
+        assert!(camera.is_oriented());
+        for camera in [
+            Camera {
+                up: Vec3::ZERO,
+                ..camera
+            },
+            Camera {
+                up: Vec3::Z * -2.0,
+                ..camera
+            },
+            Camera {
+                position: camera.target,
+                ..camera
+            },
+        ] {
+            assert!(!camera.is_oriented());
+        }
+    }
+
+    #[test]
+    fn orthographic_eye_is_along_the_view_direction() {
+        let mut camera = Camera::default();
+        let p = Vec3::new(100.0, 0.0, 0.0);
+        assert!(camera.eye_direction(p).x < 0.0);
+        camera.projection = Projection::Orthographic;
+        assert_eq!(camera.eye_direction(p), Vec3::Z);
+    }
+
+    #[test]
+    fn fitted_sphere_fills_the_view() {
+        let viewport = Rect::from_min_size(pos2(0.0, 0.0), vec2(300.0, 600.0));
+        let mut camera = Camera::default();
+        camera.set_distance(camera.fit_distance(2.0, aspect(viewport)));
+        let vp = camera.projection_matrix(aspect(viewport)) * camera.view();
+        // En un área alta manda el ancho: la esfera llega casi a los lados
+        let edge = (vp * Vec3::new(2.0, 0.0, 0.0).extend(1.0)).project();
+        assert!(edge.x > 0.8 && edge.x < 1.0);
+    }
+
+    #[test]
+    fn arcball_follows_the_cursor() {
+        let mut camera = Camera::looking_at(Vec3::ZERO, 10.0);
+        let viewport = Rect::from_min_size(pos2(0.0, 0.0), vec2(200.0, 200.0));
+        // Arrastrar hacia la derecha gira la escena alrededor de la vertical
+        let (axis, angle) = camera
+            .arcball(viewport, pos2(100.0, 100.0), pos2(150.0, 100.0))
+            .unwrap();
+        assert!((axis - Vec3::Y).length() < 1e-5 && angle > 0.0);
+        // La cámara gira al revés: el punto de delante se va a la derecha
+        camera.orbit(axis, -angle);
+        assert!((camera.distance() - 10.0).abs() < 1e-4);
+        assert!(camera.view().transform_point(Vec3::Z).x > 0.0);
+    }
+
+    #[test]
+    fn ray_through_the_centre_hits_the_target() {
+        let viewport = Rect::from_min_size(pos2(10.0, 20.0), vec2(300.0, 100.0));
+        for projection in [Projection::Perspective, Projection::Orthographic] {
+            let camera = Camera {
+                projection,
+                ..Camera::looking_at(Vec3::new(1.0, 2.0, 3.0), 10.0)
+            };
+            let (origin, dir) = camera.ray(viewport, viewport.center()).unwrap();
+            let t = (camera.target - origin).dot(dir);
+            assert!((origin + dir * t - camera.target).length() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn presets_look_at_the_target() {
+        let mut camera = Camera::looking_at(Vec3::new(1.0, 2.0, 3.0), 10.0);
+        for preset in ViewPreset::ALL {
+            camera.set_preset(preset);
+            assert!((camera.distance() - 10.0).abs() < 1e-4);
+            // El objetivo queda en el centro de la pantalla
+            let p = camera.view().transform_point(camera.target);
+            assert!(p.x.abs() < 1e-4 && p.y.abs() < 1e-4);
+        }
+        // Longitud en pantalla de los ejes X, Y y Z
+        let mut lengths = |preset| {
+            camera.set_preset(preset);
+            let view = camera.view();
+            [Vec3::X, Vec3::Y, Vec3::Z].map(|a| {
+                let p = view.transform_vector(a);
+                p.x.hypot(p.y)
+            })
+        };
+        // En la isométrica los tres ejes se ven igual de largos
+        let [x, y, z] = lengths(ViewPreset::Isometric);
+        assert!((x - y).abs() < 1e-3 && (y - z).abs() < 1e-3);
+        // En la dimétrica X y Z son iguales y el Y mide la mitad (1:½:1)
+        let [x, y, z] = lengths(ViewPreset::Dimetric);
+        assert!((x - z).abs() < 1e-3, "{x} {z}");
+        assert!((y - x / 2.0).abs() < 1e-3, "{x} {y}");
+    }
+}
@@ -0,0 +1,361 @@
+// Copyright (C) 2026  Antonio-Miguel Corbi Bellot
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+// Argumentos de la línea de órdenes del visor
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use egui::Color32;
+
+use crate::anim::{MAX_FRAMES, MAX_STEP, MIN_STEP};
+use crate::camera::{MAX_ZOOM, MIN_ZOOM};
+use crate::render::{MAX_ANGLE_STEP, MIN_ANGLE_STEP};
+
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+pub enum RotateAxis {
+    X,
+    Y,
+    Z,
+    // Sin giro: el modelo queda quieto
+    None,
+}
+
+#[derive(Debug, Parser)]
+#[command(
+    version,
+    about = "Penger3D: a small 3D model viewer",
+    args_conflicts_with_subcommands = true
+)]
+pub struct Cli {
+    /// Run without a window instead of opening the viewer
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    /// Model to open (.obj, .stl, .ply, .off, .gltf or .glb)
+    pub model: Option<String>,
+
+    /// Axes to spin the model around, comma separated (e.g. "x,y" or "none")
+    #[arg(long, value_enum, value_delimiter = ',', value_name = "AXES")]
+    pub rotate: Option<Vec<RotateAxis>>,
+
+    /// Rotation speed in degrees per second
+    #[arg(long, value_name = "DEG_PER_SEC", value_parser = angle_step)]
+    pub angle_step: Option<f32>,
+
+    /// Initial zoom (1 fits the model to the window)
+    #[arg(long, value_parser = zoom)]
+    pub zoom: Option<f32>,
+
+    /// Draw the edges of the faces (combine with --points to draw both)
+    #[arg(long)]
+    pub wireframe: bool,
+
+    /// Draw the vertices (combine with --wireframe to draw both)
+    #[arg(long)]
+    pub points: bool,
+
+    /// Initial window size
+    #[arg(long, value_name = "WIDTHxHEIGHT", value_parser = window_size)]
+    pub window_size: Option<[f32; 2]>,
+
+    /// Background colour of the drawing area
+    #[arg(long, value_name = "#RRGGBB", value_parser = background)]
+    pub background: Option<Color32>,
+}
+
+// -- Órdenes sin ventana: -----------------------------------------------
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Print vertex, face and edge counts and the bounds of a model
+    Stats {
+        /// Model to inspect
+        model: String,
+    },
+    /// Convert a model to the format given by the output extension
+    Convert {
+        /// Model to read (.obj, .stl, .ply, .off, .gltf or .glb)
+        input: String,
+        /// File to write (.obj, .stl or .ply)
+        output: String,
+        /// Write STL or PLY as text instead of binary
+        #[arg(long)]
+        ascii: bool,
+    },
+    /// Render a model to a PNG image
+    Render(RenderArgs),
+    /// Record a turn of the model as an animated GIF or a PNG sequence
+    Animate(AnimateArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct RenderArgs {
+    /// Model to render
+    pub model: String,
+
+    /// PNG file to write
+    #[arg(short, long)]
+    pub output: String,
+
+    /// Rotation in degrees around the --axis axes
+    #[arg(long, default_value_t = 0.0, allow_negative_numbers = true)]
+    pub angle: f32,
+
+    #[command(flatten)]
+    pub look: LookArgs,
+}
+
+#[derive(Debug, Args)]
+pub struct AnimateArgs {
+    /// Model to animate
+    pub model: String,
+
+    /// GIF file to write, or PNG file name for a numbered sequence
+    /// (spin.png gives spin_0000.png, spin_0001.png, …)
+    #[arg(short, long)]
+    pub output: String,
+
+    /// Number of frames, at most 3600 (a full turn by default)
+    #[arg(long, value_parser = frames)]
+    pub frames: Option<usize>,
+
+    /// Degrees rotated between frames, at least 0.1 either way (a full turn
+    /// by default)
+    #[arg(long, value_name = "DEGREES", allow_negative_numbers = true, value_parser = step)]
+    pub step: Option<f32>,
+
+    /// Frames per second of the GIF
+    #[arg(long, default_value_t = 12.0)]
+    pub fps: f32,
+
+    /// Times the GIF is repeated (forever by default)
+    #[arg(long)]
+    pub loops: Option<u16>,
+
+    #[command(flatten)]
+    pub look: LookArgs,
+}
+
+// Aspecto de la imagen: común a render y animate
+#[derive(Debug, Args)]
+pub struct LookArgs {
+    /// Axes to rotate around, comma separated
+    #[arg(long, value_enum, value_delimiter = ',', default_value = "y")]
+    pub axis: Vec<RotateAxis>,
+
+    /// Image size (at most 16384 pixels per side)
+    #[arg(long, value_name = "WIDTHxHEIGHT", default_value = "800x600", value_parser = image_size)]
+    pub size: [f32; 2],
+
+    /// Zoom [default: 1, the model fits the image]
+    #[arg(long, value_parser = zoom)]
+    pub zoom: Option<f32>,
+
+    /// Draw the edges of the faces (the default)
+    #[arg(long)]
+    pub wireframe: bool,
+
+    /// Draw the vertices
+    #[arg(long)]
+    pub points: bool,
+
+    /// Fill the faces with their material colour (hiding what is behind)
+    #[arg(long)]
+    pub solid: bool,
+
+    /// Draw the bounding box of the model
+    #[arg(long)]
+    pub bbox: bool,
+
+    /// Background colour
+    #[arg(long, value_name = "#RRGGBB", default_value = "#323232", value_parser = background)]
+    pub background: Color32,
+}
+
+// -- Validación de valores: ----------------------------------------------
+fn in_range(s: &str, min: f32, max: f32) -> Result<f32, String> {
+    let v: f32 = s.parse().map_err(|_| format!("'{s}' is not a number"))?;
+    if (min..=max).contains(&v) {
+        Ok(v)
+    } else {
+        Err(format!("{v} is not in {min}..={max}"))
+    }
+}
+
+fn angle_step(s: &str) -> Result<f32, String> {
+    in_range(s, MIN_ANGLE_STEP, MAX_ANGLE_STEP)
+}
+
+fn frames(s: &str) -> Result<usize, String> {
+    let n: usize = s
+        .parse()
+        .map_err(|_| format!("'{s}' is not a number of frames"))?;
+    if (1..=MAX_FRAMES).contains(&n) {
+        Ok(n)
+    } else {
+        Err(format!("{n} is not in 1..={MAX_FRAMES}"))
+    }
+}
+
+// Paso de la animación: en cualquier sentido, pero no tan pequeño que una
+// vuelta necesite más de MAX_FRAMES fotogramas
+fn step(s: &str) -> Result<f32, String> {
+    let v = in_range(s, -MAX_STEP, MAX_STEP)?;
+    if v.abs() < MIN_STEP {
+        return Err(format!("{v} is smaller than {MIN_STEP} degrees"));
+    }
+    Ok(v)
+}
+
+fn zoom(s: &str) -> Result<f32, String> {
+    in_range(s, MIN_ZOOM, MAX_ZOOM)
+}
+
+fn window_size(s: &str) -> Result<[f32; 2], String> {
+    let err = || format!("'{s}' is not a size like 1024x768");
+    let (w, h) = s.split_once(['x', 'X']).ok_or_else(err)?;
+    let w: f32 = w.trim().parse().map_err(|_| err())?;
+    let h: f32 = h.trim().parse().map_err(|_| err())?;
+    // f32 también admite "inf" y "NaN"
+    if w.is_finite() && h.is_finite() && w > 0.0 && h > 0.0 {
+        Ok([w, h])
+    } else {
+        Err(err())
+    }
+}
+
+// Como `window_size`, pero la imagen necesita al menos un píxel por lado
+fn image_size(s: &str) -> Result<[f32; 2], String> {
+    let [w, h] = window_size(s)?;
+    if w >= 1.0 && h >= 1.0 {
+        Ok([w, h])
+    } else {
+        Err(format!("'{s}' is smaller than one pixel per side"))
+    }
+}
+
+// "#rrggbb" o "#rrggbbaa" (el '#' es opcional)
+fn background(s: &str) -> Result<Color32, String> {
+    let hex = s.strip_prefix('#').unwrap_or(s);
+    Color32::from_hex(&format!("#{hex}")).map_err(|_| format!("'{s}' is not a colour like #323232"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Cli, clap::Error> {
+        Cli::try_parse_from(std::iter::once("formulars").chain(args.iter().copied()))
+    }
+
+    #[test]
+    fn viewer_options() {
+        let cli = parse(&[
+            "model.obj",
+            "--rotate",
+            "x,y",
+            "--angle-step",
+            "30",
+            "--window-size",
+            "1024x768",
+            "--background",
+            "ff0000",
+        ])
+        .unwrap();
+        assert!(cli.command.is_none());
+        assert_eq!(cli.model.as_deref(), Some("model.obj"));
+        assert_eq!(cli.rotate, Some(vec![RotateAxis::X, RotateAxis::Y]));
+        assert_eq!(cli.angle_step, Some(30.0));
+        assert_eq!(cli.window_size, Some([1024.0, 768.0]));
+        assert_eq!(cli.background, Some(Color32::RED));
+
+        assert!(parse(&["--zoom", "100"]).is_err());
+        assert!(parse(&["--angle-step", "400"]).is_err());
+        assert!(parse(&["--rotate", "w"]).is_err());
+        // Un modelo suelto no se mezcla con una orden
+        assert!(parse(&["model.obj", "stats", "other.obj"]).is_err());
+    }
+
+    #[test]
+    fn subcommands() {
+        let cli = parse(&["stats", "a.obj"]).unwrap();
+        assert!(matches!(cli.command, Some(Command::Stats { model }) if model == "a.obj"));
+
+        let cli = parse(&["convert", "a.obj", "b.stl", "--ascii"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Command::Convert { input, output, ascii: true }) if input == "a.obj" && output == "b.stl"
+        ));
+
+        let cli = parse(&[
+            "render", "a.obj", "-o", "a.png", "--angle", "-45", "--axis", "x,z",
+        ])
+        .unwrap();
+        let Some(Command::Render(args)) = cli.command else {
+            panic!("expected render");
+        };
+        assert_eq!(args.angle, -45.0);
+        assert_eq!(args.look.axis, vec![RotateAxis::X, RotateAxis::Z]);
+        assert_eq!(args.look.size, [800.0, 600.0]);
+        assert_eq!(args.look.background, Color32::from_gray(0x32));
+        assert!(parse(&["render", "a.obj"]).is_err());
+
+        let cli = parse(&[
+            "animate", "a.obj", "-o", "a.gif", "--step", "-10", "--size", "64x48",
+        ])
+        .unwrap();
+        let Some(Command::Animate(args)) = cli.command else {
+            panic!("expected animate");
+        };
+        assert_eq!(
+            (args.frames, args.step, args.fps),
+            (None, Some(-10.0), 12.0)
+        );
+        assert_eq!(args.look.size, [64.0, 48.0]);
+
+        // Ni demasiados fotogramas ni pasos minúsculos
+        let animate =
+            |extra: &[&str]| parse(&[&["animate", "a.obj", "-o", "a.gif"], extra].concat());
+        assert!(animate(&["--frames", "3600", "--step", "0.1"]).is_ok());
+        assert!(animate(&["--step", "-0.1"]).is_ok());
+        for bad in [
+            ["--frames", "0"],
+            ["--frames", "3601"],
+            ["--step", "0.001"],
+            ["--step", "0"],
+            ["--step", "-400"],
+        ] {
+            assert!(animate(&bad).is_err(), "{bad:?}");
+        }
+    }
+
+    #[test]
+    fn sizes_must_be_finite_and_positive() {
+        assert_eq!(window_size("640x480"), Ok([640.0, 480.0]));
+        assert_eq!(window_size(" 640 X 480 "), Ok([640.0, 480.0]));
+        for bad in [
+            "640", "x480", "0x480", "640x-1", "infx480", "640xNaN", "640xinf", "axb",
+        ] {
+            assert!(window_size(bad).is_err(), "{bad}");
+        }
+        assert!(parse(&["--window-size", "infxinf"]).is_err());
+        assert!(parse(&["render", "a.obj", "-o", "a.png", "--size", "NaNx10"]).is_err());
+
+        // Una ventana puede medir medio punto; una imagen, no
+        assert_eq!(window_size("0.5x0.5"), Ok([0.5, 0.5]));
+        assert!(image_size("0.5x0.5").is_err());
+        assert_eq!(image_size("1x1"), Ok([1.0, 1.0]));
+        assert!(parse(&["render", "a.obj", "-o", "a.png", "--size", "0.5x0.5"]).is_err());
+        assert!(parse(&["animate", "a.obj", "-o", "a.gif", "--size", "64x0.5"]).is_err());
+    }
+}
@@ -0,0 +1,837 @@
+// Copyright (C) 2026  Antonio-Miguel Corbi Bellot
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+// Lector de glTF 2.0 (.gltf con buffers externos o "data:" y .glb) para
+// mallas estáticas: se recorre la jerarquía de nodos aplicando sus
+// transformaciones y se unen todas las primitivas en una sola malla
+
+use std::fs;
+use std::path::Path;
+
+use serde_json::Value;
+
+use super::{LoadError, ParseReason};
+use crate::types::{Corner, Face, Mesh, Point2D, Point3D};
+
+const GLB_MAGIC: &[u8] = b"glTF";
+const CHUNK_JSON: u32 = 0x4E4F_534A;
+const CHUNK_BIN: u32 = 0x004E_4942;
+
+// Modos de primitiva de glTF
+const MODE_LINES: u64 = 1;
+const MODE_LINE_LOOP: u64 = 2;
+const MODE_LINE_STRIP: u64 = 3;
+const MODE_TRIANGLES: u64 = 4;
+const MODE_TRIANGLE_STRIP: u64 = 5;
+const MODE_TRIANGLE_FAN: u64 = 6;
+
+// Matriz 4x4 en columnas, como la guarda glTF
+type Mat = [f32; 16];
+
+const IDENTITY: Mat = [
+    1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0,
+];
+
+type GltfResult<T> = Result<T, ParseReason>;
+
+fn invalid<T>(msg: impl Into<String>) -> GltfResult<T> {
+    Err(ParseReason::Invalid(msg.into()))
+}
+
+// -- Matrices: -----------------------------------------------------------
+fn mat_mul(a: &Mat, b: &Mat) -> Mat {
+    let mut m = [0.0; 16];
+    for c in 0..4 {
+        for r in 0..4 {
+            m[c * 4 + r] = (0..4).map(|k| a[k * 4 + r] * b[c * 4 + k]).sum();
+        }
+    }
+    m
+}
+
+// Matriz de un nodo: "matrix" o la composición T·R·S
+fn node_matrix(node: &Value) -> Mat {
+    let floats = |key: &str, default: &[f32]| -> Vec<f32> {
+        node[key].as_array().map_or(default.to_vec(), |a| {
+            a.iter().map(|v| v.as_f64().unwrap_or(0.0) as f32).collect()
+        })
+    };
+    if node.get("matrix").is_some() {
+        let m = floats("matrix", &IDENTITY);
+        if let Ok(m) = m.try_into() {
+            return m;
+        }
+    }
+    let t = floats("translation", &[0.0, 0.0, 0.0]);
+    let q = floats("rotation", &[0.0, 0.0, 0.0, 1.0]);
+    let s = floats("scale", &[1.0, 1.0, 1.0]);
+    if t.len() < 3 || q.len() < 4 || s.len() < 3 {
+        return IDENTITY;
+    }
+    let (x, y, z, w) = (q[0], q[1], q[2], q[3]);
+    #[rustfmt::skip]
+    let r = [
+        1.0 - 2.0 * (y * y + z * z), 2.0 * (x * y + z * w), 2.0 * (x * z - y * w), 0.0,
+        2.0 * (x * y - z * w), 1.0 - 2.0 * (x * x + z * z), 2.0 * (y * z + x * w), 0.0,
+        2.0 * (x * z + y * w), 2.0 * (y * z - x * w), 1.0 - 2.0 * (x * x + y * y), 0.0,
+        0.0, 0.0, 0.0, 1.0,
+    ];
+    let mut m = r;
+    for c in 0..3 {
+        for row in 0..3 {
+            m[c * 4 + row] *= s[c];
+        }
+    }
+    m[12] = t[0];
+    m[13] = t[1];
+    m[14] = t[2];
+    m
+}
+
+fn transform_point(m: &Mat, p: [f32; 3]) -> Point3D {
+    let f = |r: usize| m[r] * p[0] + m[4 + r] * p[1] + m[8 + r] * p[2] + m[12 + r];
+    Point3D {
+        x: f(0),
+        y: f(1),
+        z: f(2),
+    }
+}
+
+// Las normales se transforman con la inversa traspuesta de la parte 3x3
+// (su matriz adjunta basta, porque luego se normalizan)
+fn transform_normal(m: &Mat, n: [f32; 3]) -> Point3D {
+    let a = |r: usize, c: usize| m[c * 4 + r];
+    let cof = |r: usize, c: usize| {
+        let (r1, r2) = ((r + 1) % 3, (r + 2) % 3);
+        let (c1, c2) = ((c + 1) % 3, (c + 2) % 3);
+        a(r1, c1) * a(r2, c2) - a(r1, c2) * a(r2, c1)
+    };
+    let f = |r: usize| cof(r, 0) * n[0] + cof(r, 1) * n[1] + cof(r, 2) * n[2];
+    let (x, y, z) = (f(0), f(1), f(2));
+    let len = (x * x + y * y + z * z).sqrt();
+    if len > 0.0 {
+        Point3D {
+            x: x / len,
+            y: y / len,
+            z: z / len,
+        }
+    } else {
+        Point3D { x, y, z }
+    }
+}
+
+// -- Contenedor y buffers: -----------------------------------------------
+// JSON y bloque binario opcional de un .glb
+type GlbChunks<'a> = (&'a [u8], Option<&'a [u8]>);
+
+fn split_glb(bytes: &[u8]) -> Result<GlbChunks<'_>, (usize, ParseReason)> {
+    let u32_at = |off: usize| -> Result<u32, (usize, ParseReason)> {
+        bytes
+            .get(off..off + 4)
+            .map(|b| u32::from_le_bytes(b.try_into().unwrap()))
+            .ok_or((
+                bytes.len(),
+                ParseReason::Truncated {
+                    expected: off + 4,
+                    found: bytes.len(),
+                },
+            ))
+    };
+    let version = u32_at(4)?;
+    if version != 2 {
+        return Err((
+            4,
+            ParseReason::Unsupported(format!("glTF version {version}")),
+        ));
+    }
+    let mut json = None;
+    let mut bin = None;
+    let mut off = 12;
+    while off < bytes.len() {
+        let len = u32_at(off)? as usize;
+        let kind = u32_at(off + 4)?;
+        let data = bytes.get(off + 8..off + 8 + len).ok_or((
+            bytes.len(),
+            ParseReason::Truncated {
+                expected: off + 8 + len,
+                found: bytes.len(),
+            },
+        ))?;
+        match kind {
+            CHUNK_JSON => json = Some(data),
+            CHUNK_BIN if bin.is_none() => bin = Some(data),
+            _ => {}
+        }
+        off += 8 + len;
+    }
+    let json = json.ok_or((12, ParseReason::Invalid("missing JSON chunk".to_string())))?;
+    Ok((json, bin))
+}
+
+fn decode_base64(data: &str) -> GltfResult<Vec<u8>> {
+    let value = |c: u8| match c {
+        b'A'..=b'Z' => Some(c - b'A'),
+        b'a'..=b'z' => Some(c - b'a' + 26),
+        b'0'..=b'9' => Some(c - b'0' + 52),
+        b'+' | b'-' => Some(62),
+        b'/' | b'_' => Some(63),
+        _ => None,
+    };
+    let mut out = Vec::with_capacity(data.len() * 3 / 4);
+    let mut acc = 0u32;
+    let mut bits = 0;
+    for c in data
+        .bytes()
+        .filter(|c| !c.is_ascii_whitespace() && *c != b'=')
+    {
+        let Some(v) = value(c) else {
+            return invalid(format!("bad base64 character '{}'", c as char));
+        };
+        acc = (acc << 6) | v as u32;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            out.push((acc >> bits) as u8);
+        }
+    }
+    Ok(out)
+}
+
+fn load_buffers(doc: &Value, dir: &Path, bin: Option<&[u8]>) -> GltfResult<Vec<Vec<u8>>> {
+    let Some(buffers) = doc["buffers"].as_array() else {
+        return Ok(vec![]);
+    };
+    buffers
+        .iter()
+        .enumerate()
+        .map(|(i, b)| {
+            let data = match b["uri"].as_str() {
+                // Sin URI: el bloque BIN del .glb
+                None => match bin {
+                    Some(bin) if i == 0 => bin.to_vec(),
+                    _ => return invalid(format!("buffer {i} has no uri")),
+                },
+                Some(uri) if uri.starts_with("data:") => {
+                    let Some((_, payload)) = uri.split_once(";base64,") else {
+                        return Err(ParseReason::Unsupported("non-base64 data uri".to_string()));
+                    };
+                    decode_base64(payload)?
+                }
+                Some(uri) => fs::read(dir.join(uri))
+                    .map_err(|e| ParseReason::Invalid(format!("buffer '{uri}': {e}")))?,
+            };
+            let len = b["byteLength"].as_u64().unwrap_or(0) as usize;
+            if data.len() < len {
+                return Err(ParseReason::Truncated {
+                    expected: len,
+                    found: data.len(),
+                });
+            }
+            Ok(data)
+        })
+        .collect()
+}
+
+// -- Lectura de glTF: ----------------------------------------------------
+// Accessor ya validado: tipo de sus componentes y, si tiene bufferView, sus
+// bytes, el desplazamiento del primer elemento y la separación entre ellos
+struct Accessor<'b> {
+    n: usize,
+    ctype: u64,
+    size: usize,
+    count: usize,
+    normalized: bool,
+    data: Option<(&'b [u8], usize, usize)>,
+}
+
+impl Accessor<'_> {
+    // Bytes del componente `c` del elemento `i` (None si todo vale cero)
+    fn component(&self, i: usize, c: usize) -> Option<&[u8]> {
+        let (bytes, start, stride) = self.data?;
+        let off = start + i * stride + c * self.size;
+        Some(&bytes[off..off + self.size])
+    }
+}
+
+struct Gltf<'a> {
+    doc: &'a Value,
+    buffers: Vec<Vec<u8>>,
+    mesh: Mesh,
+    // Material de la malla asignado a cada material glTF
+    materials: Vec<Option<usize>>,
+}
+
+impl<'a> Gltf<'a> {
+    fn item(&self, kind: &str, idx: &Value) -> GltfResult<&'a Value> {
+        let Some(i) = idx.as_u64() else {
+            return invalid(format!("bad {kind} reference"));
+        };
+        match self.doc[kind].get(i as usize) {
+            Some(v) => Ok(v),
+            None => invalid(format!("{kind}[{i}] does not exist")),
+        }
+    }
+
+    // Comprueba un accessor y localiza sus datos. `count` viene del JSON,
+    // así que antes de reservar memoria hay que ver que cabe en la
+    // bufferView y que esta cabe en su buffer
+    fn layout(&self, idx: &Value) -> GltfResult<Accessor<'_>> {
+        let acc = self.item("accessors", idx)?;
+        if acc.get("sparse").is_some() {
+            return Err(ParseReason::Unsupported("sparse accessors".to_string()));
+        }
+        let n = match acc["type"].as_str() {
+            Some("SCALAR") => 1,
+            Some("VEC2") => 2,
+            Some("VEC3") => 3,
+            Some("VEC4") => 4,
+            t => return invalid(format!("accessor type {t:?}")),
+        };
+        let ctype = acc["componentType"].as_u64().unwrap_or(0);
+        let size = match ctype {
+            5120 | 5121 => 1,
+            5122 | 5123 => 2,
+            5125 | 5126 => 4,
+            c => return invalid(format!("component type {c}")),
+        };
+        let count = acc["count"].as_u64().unwrap_or(0) as usize;
+        let mut layout = Accessor {
+            n,
+            ctype,
+            size,
+            count,
+            normalized: acc["normalized"].as_bool().unwrap_or(false),
+            data: None,
+        };
+
+        // Un accessor sin bufferView vale todo ceros; aun así no puede
+        // describir más bytes de los que trae el fichero
+        let Some(view_idx) = acc.get("bufferView") else {
+            let total: usize = self.buffers.iter().map(Vec::len).sum();
+            if count.checked_mul(n * size).is_none_or(|len| len > total) {
+                return invalid(format!("accessor of {count} elements without data"));
+            }
+            return Ok(layout);
+        };
+        let view = self.item("bufferViews", view_idx)?;
+        let buffer = self
+            .buffers
+            .get(view["buffer"].as_u64().unwrap_or(0) as usize)
+            .ok_or(ParseReason::Invalid(
+                "bufferView without buffer".to_string(),
+            ))?;
+        let view_start = view["byteOffset"].as_u64().unwrap_or(0) as usize;
+        let view_len = view["byteLength"].as_u64().unwrap_or(0) as usize;
+        let view_end = view_start.saturating_add(view_len);
+        let Some(bytes) = buffer.get(view_start..view_end) else {
+            return Err(ParseReason::Truncated {
+                expected: view_end,
+                found: buffer.len(),
+            });
+        };
+        let start = acc["byteOffset"].as_u64().unwrap_or(0) as usize;
+        let stride = view["byteStride"].as_u64().map_or(n * size, |s| s as usize);
+
+        // El último elemento tiene que acabar dentro de la bufferView
+        let end = match count.checked_sub(1) {
+            None => Some(0),
+            Some(last) => last
+                .checked_mul(stride)
+                .and_then(|off| off.checked_add(start))
+                .and_then(|off| off.checked_add(n * size)),
+        };
+        if end.is_none_or(|end| end > bytes.len()) {
+            return invalid(format!(
+                "accessor of {count} elements does not fit in its bufferView ({} bytes)",
+                bytes.len()
+            ));
+        }
+        layout.data = Some((bytes, start, stride));
+        Ok(layout)
+    }
+
+    // Lee un accessor como lista de elementos de `n` componentes reales
+    fn accessor(&self, idx: &Value) -> GltfResult<(usize, Vec<f32>)> {
+        let acc = self.layout(idx)?;
+        let scale = |max: f32| if acc.normalized { max } else { 1.0 };
+        let mut out = Vec::with_capacity(acc.n * acc.count);
+        for i in 0..acc.count {
+            for c in 0..acc.n {
+                let Some(b) = acc.component(i, c) else {
+                    out.push(0.0);
+                    continue;
+                };
+                let v = match acc.ctype {
+                    5120 => b[0] as i8 as f32 / scale(127.0),
+                    5121 => b[0] as f32 / scale(255.0),
+                    5122 => i16::from_le_bytes([b[0], b[1]]) as f32 / scale(32767.0),
+                    5123 => u16::from_le_bytes([b[0], b[1]]) as f32 / scale(65535.0),
+                    5125 => u32::from_le_bytes(b.try_into().unwrap()) as f32,
+                    _ => f32::from_le_bytes(b.try_into().unwrap()),
+                };
+                out.push(v);
+            }
+        }
+        Ok((acc.n, out))
+    }
+
+    // Atributo de vértice con `n` componentes por elemento y, si se indica,
+    // tantos elementos como posiciones: si no, `ns`/`uvs` dejarían de ir a
+    // la par que `vs`
+    fn attribute(
+        &self,
+        idx: &Value,
+        name: &str,
+        n: usize,
+        count: Option<usize>,
+    ) -> GltfResult<Vec<f32>> {
+        let (found, values) = self.accessor(idx)?;
+        if found != n {
+            return invalid(format!("{name} must have {n} components, found {found}"));
+        }
+        if let Some(count) = count
+            && values.len() != count * n
+        {
+            return invalid(format!(
+                "{name} has {} elements but POSITION has {count}",
+                values.len() / n
+            ));
+        }
+        Ok(values)
+    }
+
+    // Los índices se leen como enteros: pasar por f32 perdería los
+    // mayores de 2^24
+    fn indices(&self, idx: &Value) -> GltfResult<Vec<usize>> {
+        let acc = self.layout(idx)?;
+        if acc.n != 1 || !matches!(acc.ctype, 5121 | 5123 | 5125) {
+            return invalid("indices must be unsigned integer scalars");
+        }
+        Ok((0..acc.count)
+            .map(|i| match acc.component(i, 0) {
+                None => 0,
+                Some(&[b]) => b as usize,
+                Some(&[b0, b1]) => u16::from_le_bytes([b0, b1]) as usize,
+                Some(b) => u32::from_le_bytes(b.try_into().unwrap()) as usize,
+            })
+            .collect())
+    }
+
+    fn material(&mut self, idx: &Value) -> GltfResult<Option<usize>> {
+        let Some(i) = idx.as_u64().map(|i| i as usize) else {
+            return Ok(None);
+        };
+        if let Some(Some(m)) = self.materials.get(i) {
+            return Ok(Some(*m));
+        }
+        let mat = self.item("materials", idx)?;
+        let name = mat["name"]
+            .as_str()
+            .map_or(format!("material_{i}"), String::from);
+        let m = self.mesh.material_index(&name);
+        if let Some(c) = mat["pbrMetallicRoughness"]["baseColorFactor"].as_array() {
+            let c: Vec<f32> = c.iter().map(|v| v.as_f64().unwrap_or(1.0) as f32).collect();
+            if c.len() == 4 {
+                self.mesh.materials[m].kd = [c[0], c[1], c[2]];
+                self.mesh.materials[m].d = c[3];
+            }
+        }
+        if self.materials.len() <= i {
+            self.materials.resize(i + 1, None);
+        }
+        self.materials[i] = Some(m);
+        Ok(Some(m))
+    }
+
+    fn primitive(&mut self, prim: &Value, m: &Mat, group: Option<usize>) -> GltfResult<()> {
+        let attrs = &prim["attributes"];
+        let Some(pos) = attrs.get("POSITION") else {
+            return Ok(());
+        };
+        let ps = self.attribute(pos, "POSITION", 3, None)?;
+        let count = ps.len() / 3;
+        let base = self.mesh.vs.len();
+        self.mesh
+            .vs
+            .extend(ps.chunks(3).map(|p| transform_point(m, [p[0], p[1], p[2]])));
+
+        let has_normals = match attrs.get("NORMAL") {
+            Some(n) => {
+                let ns = self.attribute(n, "NORMAL", 3, Some(count))?;
+                self.pad_normals(base);
+                self.mesh.ns.extend(
+                    ns.chunks(3)
+                        .map(|n| transform_normal(m, [n[0], n[1], n[2]])),
+                );
+                true
+            }
+            None => false,
+        };
+        let has_uvs = match attrs.get("TEXCOORD_0") {
+            Some(t) => {
+                let uvs = self.attribute(t, "TEXCOORD_0", 2, Some(count))?;
+                self.mesh.uvs.resize(base, Point2D { x: 0.0, y: 0.0 });
+                self.mesh
+                    .uvs
+                    .extend(uvs.chunks(2).map(|t| Point2D { x: t[0], y: t[1] }));
+                true
+            }
+            None => false,
+        };
+
+        let indices: Vec<usize> = match prim.get("indices") {
+            Some(i) => self.indices(i)?,
+            None => (0..count).collect(),
+        };
+        if let Some(&i) = indices.iter().find(|&&i| i >= count) {
+            return Err(ParseReason::IndexOutOfRange {
+                index: i as i64,
+                count,
+            });
+        }
+        let corner = |i: usize| Corner {
+            v: base + i,
+            vt: has_uvs.then_some(base + i),
+            vn: has_normals.then_some(base + i),
+        };
+
+        let material = self.material(&prim["material"])?;
+        let mut triangle = |a: usize, b: usize, c: usize| {
+            self.mesh.fs.push(Face {
+                corners: vec![corner(a), corner(b), corner(c)],
+                group,
+                smoothing: 0,
+                material,
+            });
+        };
+        let n = indices.len();
+        match prim["mode"].as_u64().unwrap_or(MODE_TRIANGLES) {
+            MODE_TRIANGLES => {
+                for t in indices.chunks_exact(3) {
+                    triangle(t[0], t[1], t[2]);
+                }
+            }
+            MODE_TRIANGLE_STRIP => {
+                for i in 0..n.saturating_sub(2) {
+                    let (a, b) = if i % 2 == 0 { (i, i + 1) } else { (i + 1, i) };
+                    triangle(indices[a], indices[b], indices[i + 2]);
+                }
+            }
+            MODE_TRIANGLE_FAN => {
+                for i in 1..n.saturating_sub(1) {
+                    triangle(indices[0], indices[i], indices[i + 1]);
+                }
+            }
+            MODE_LINES => {
+                for l in indices.chunks_exact(2) {
+                    self.mesh.ls.push(vec![base + l[0], base + l[1]]);
+                }
+            }
+            mode @ (MODE_LINE_LOOP | MODE_LINE_STRIP) => {
+                let mut l: Vec<usize> = indices.iter().map(|&i| base + i).collect();
+                if mode == MODE_LINE_LOOP && n > 0 {
+                    l.push(base + indices[0]);
+                }
+                self.mesh.ls.push(l);
+            }
+            // Los puntos sueltos ya están en `vs`
+            _ => {}
+        }
+        Ok(())
+    }
+
+    // Mantiene `ns` alineado con `vs` cuando unas primitivas traen
+    // normales y otras no
+    fn pad_normals(&mut self, len: usize) {
+        let zero = Point3D {
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+        };
+        self.mesh.ns.resize(len, zero);
+    }
+
+    fn node(&mut self, idx: &Value, parent: &Mat, depth: usize) -> GltfResult<()> {
+        if depth > 64 {
+            return invalid("node hierarchy too deep (cycle?)");
+        }
+        let node = self.item("nodes", idx)?;
+        let m = mat_mul(parent, &node_matrix(node));
+        if let Some(mesh_idx) = node.get("mesh") {
+            let mesh = self.item("meshes", mesh_idx)?;
+            let group = mesh["name"].as_str().map(|name| {
+                self.mesh.groups.push(name.to_string());
+                self.mesh.groups.len() - 1
+            });
+            for prim in mesh["primitives"].as_array().into_iter().flatten() {
+                self.primitive(prim, &m, group)?;
+            }
+        }
+        if let Some(children) = node["children"].as_array() {
+            for child in children {
+                self.node(child, &m, depth + 1)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn read_document(doc: &Value, buffers: Vec<Vec<u8>>) -> GltfResult<Mesh> {
+    let mut gltf = Gltf {
+        doc,
+        buffers,
+        mesh: Mesh::default(),
+        materials: vec![],
+    };
+
+    // Escena por defecto, la primera, o todos los nodos raíz si no hay
+    let scene = doc["scene"].as_u64().unwrap_or(0) as usize;
+    let roots: Vec<Value> = match doc["scenes"].get(scene) {
+        Some(s) => s["nodes"].as_array().cloned().unwrap_or_default(),
+        None => {
+            let nodes = doc["nodes"].as_array().map_or(0, Vec::len);
+            let children: Vec<u64> = doc["nodes"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .flat_map(|n| n["children"].as_array().cloned().unwrap_or_default())
+                .filter_map(|c| c.as_u64())
+                .collect();
+            (0..nodes as u64)
+                .filter(|i| !children.contains(i))
+                .map(Value::from)
+                .collect()
+        }
+    };
+    for root in &roots {
+        gltf.node(root, &IDENTITY, 0)?;
+    }
+
+    let mut mesh = gltf.mesh;
+    if !mesh.ns.is_empty() {
+        let zero = Point3D {
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+        };
+        mesh.ns.resize(mesh.vs.len(), zero);
+    }
+    if !mesh.uvs.is_empty() {
+        mesh.uvs.resize(mesh.vs.len(), Point2D { x: 0.0, y: 0.0 });
+    }
+    Ok(mesh)
+}
+
+pub fn read_gltf(fname: &str) -> Result<Mesh, LoadError> {
+    let bytes = fs::read(fname).map_err(|source| LoadError::Io {
+        file: fname.to_string(),
+        source,
+    })?;
+
+    let (json, bin) = if bytes.starts_with(GLB_MAGIC) {
+        split_glb(&bytes).map_err(|(offset, reason)| LoadError::Corrupt {
+            file: fname.to_string(),
+            offset,
+            reason,
+        })?
+    } else {
+        (&bytes[..], None)
+    };
+
+    let doc: Value = serde_json::from_slice(json).map_err(|e| LoadError::Parse {
+        file: fname.to_string(),
+        line: e.line(),
+        column: e.column(),
+        reason: ParseReason::Invalid(e.to_string()),
+    })?;
+
+    let invalid = |reason| LoadError::Invalid {
+        file: fname.to_string(),
+        reason,
+    };
+    let dir = Path::new(fname).parent().unwrap_or(Path::new(""));
+    let buffers = load_buffers(&doc, dir, bin).map_err(invalid)?;
+    read_document(&doc, buffers).map_err(invalid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture(name: &str) -> String {
+        format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+    }
+
+    fn close(p: Point3D, q: [f32; 3]) -> bool {
+        (p.x - q[0]).abs() < 1e-5 && (p.y - q[1]).abs() < 1e-5 && (p.z - q[2]).abs() < 1e-5
+    }
+
+    #[test]
+    fn data_uri_triangle_with_translation_and_material() {
+        let mesh = read_gltf(&fixture("triangle.gltf")).unwrap();
+        assert_eq!(mesh.vs.len(), 3);
+        assert!(close(mesh.vs[0], [1.0, 0.0, 0.0]));
+        assert!(close(mesh.vs[2], [1.0, 1.0, 0.0]));
+        assert_eq!(mesh.fs.len(), 1);
+        let m = mesh.fs[0].material.unwrap();
+        assert_eq!(mesh.materials[m].kd, [1.0, 0.0, 0.0]);
+        assert_eq!(mesh.groups, vec!["tri".to_string()]);
+    }
+
+    #[test]
+    fn external_buffer_applies_node_hierarchy() {
+        let mesh = read_gltf(&fixture("quad.gltf")).unwrap();
+        assert_eq!(mesh.vs.len(), 4);
+        assert_eq!(mesh.fs.len(), 2);
+        // Escala 2 en el padre y giro de 90º sobre X en el hijo
+        assert!(close(mesh.vs[2], [2.0, 0.0, 2.0]));
+        assert!(close(mesh.ns[0], [0.0, -1.0, 0.0]));
+        assert_eq!(mesh.uvs.len(), 4);
+        assert_eq!(mesh.fs[1].corners[2].vt, Some(3));
+    }
+
+    #[test]
+    fn glb_matches_gltf() {
+        let glb = read_gltf(&fixture("quad.glb")).unwrap();
+        let gltf = read_gltf(&fixture("quad.gltf")).unwrap();
+        assert_eq!(glb.fs, gltf.fs);
+        for (a, b) in glb.vs.iter().zip(&gltf.vs) {
+            assert!(close(*a, [b.x, b.y, b.z]));
+        }
+    }
+
+    #[test]
+    fn truncated_glb_is_reported() {
+        let bytes = fs::read(fixture("quad.glb")).unwrap();
+        let (_, reason) = split_glb(&bytes[..bytes.len() - 10]).unwrap_err();
+        assert!(matches!(reason, ParseReason::Truncated { .. }));
+    }
+
+    // Documento con un accessor de `count` elementos `ty` sobre una
+    // bufferView de `len` bytes
+    fn accessor_doc(ty: &str, ctype: u64, count: u64, len: u64) -> Value {
+        serde_json::json!({
+            "bufferViews": [{ "buffer": 0, "byteOffset": 4, "byteLength": len }],
+            "accessors": [{ "bufferView": 0, "componentType": ctype, "count": count, "type": ty }],
+        })
+    }
+
+    fn gltf(doc: &Value, buffer: Vec<u8>) -> Gltf<'_> {
+        Gltf {
+            doc,
+            buffers: vec![buffer],
+            mesh: Mesh::default(),
+            materials: vec![],
+        }
+    }
+
+    #[test]
+    fn accessor_count_must_fit_its_buffer_view() {
+        let idx = Value::from(0);
+        // 3 VEC3 reales ocupan 36 bytes
+        let doc = accessor_doc("VEC3", 5126, 3, 36);
+        assert_eq!(gltf(&doc, vec![0; 40]).accessor(&idx).unwrap().1.len(), 9);
+
+        let doc = accessor_doc("VEC3", 5126, 1 << 60, 36);
+        let err = gltf(&doc, vec![0; 40]).accessor(&idx).unwrap_err();
+        assert!(matches!(err, ParseReason::Invalid(msg) if msg.contains("does not fit")));
+
+        // La bufferView se sale del buffer
+        let doc = accessor_doc("VEC3", 5126, 3, 36);
+        let err = gltf(&doc, vec![0; 20]).accessor(&idx).unwrap_err();
+        assert_eq!(
+            err,
+            ParseReason::Truncated {
+                expected: 40,
+                found: 20
+            }
+        );
+
+        // Sin bufferView tampoco se aceptan tamaños imposibles
+        let doc = serde_json::json!({
+            "accessors": [{ "componentType": 5126, "count": 1u64 << 60, "type": "VEC3" }],
+        });
+        let err = gltf(&doc, vec![0; 40]).accessor(&idx).unwrap_err();
+        assert!(matches!(err, ParseReason::Invalid(_)));
+    }
+
+    #[test]
+    fn indices_are_read_as_integers() {
+        let idx = Value::from(0);
+        let big = (1u32 << 24) + 1;
+        let mut buffer = vec![0; 4];
+        for i in [big, u32::MAX, 7] {
+            buffer.extend(i.to_le_bytes());
+        }
+        let doc = accessor_doc("SCALAR", 5125, 3, 12);
+        assert_eq!(
+            gltf(&doc, buffer).indices(&idx).unwrap(),
+            vec![big as usize, u32::MAX as usize, 7]
+        );
+
+        let doc = accessor_doc("SCALAR", 5121, 3, 3);
+        assert_eq!(
+            gltf(&doc, vec![0, 0, 0, 0, 1, 2, 255])
+                .indices(&idx)
+                .unwrap(),
+            vec![1, 2, 255]
+        );
+
+        // Los índices reales no son válidos
+        let doc = accessor_doc("SCALAR", 5126, 1, 4);
+        assert!(gltf(&doc, vec![0; 8]).indices(&idx).is_err());
+    }
+
+    // Triángulo con un atributo `name` de tipo `ty` y `count` elementos
+    fn triangle_with(name: &str, ty: &str, count: u64) -> Value {
+        serde_json::json!({
+            "nodes": [{ "mesh": 0 }],
+            "meshes": [{ "primitives": [{ "attributes": { "POSITION": 0, name: 1 } }] }],
+            "bufferViews": [{ "buffer": 0, "byteLength": 48 }],
+            "accessors": [
+                { "bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3" },
+                { "bufferView": 0, "componentType": 5126, "count": count, "type": ty },
+            ],
+        })
+    }
+
+    #[test]
+    fn attributes_must_match_the_positions() {
+        let ok = read_document(&triangle_with("NORMAL", "VEC3", 3), vec![vec![0; 48]]);
+        assert_eq!(ok.unwrap().ns.len(), 3);
+        let ok = read_document(&triangle_with("TEXCOORD_0", "VEC2", 3), vec![vec![0; 48]]);
+        assert_eq!(ok.unwrap().uvs.len(), 3);
+
+        for (name, ty, count) in [
+            ("POSITION", "VEC2", 3),
+            ("NORMAL", "VEC2", 3),
+            ("TEXCOORD_0", "VEC3", 3),
+            ("NORMAL", "VEC3", 2),
+            ("TEXCOORD_0", "VEC2", 4),
+        ] {
+            let mut doc = triangle_with(name, ty, count);
+            if name == "POSITION" {
+                // El atributo malo es la propia posición
+                doc["meshes"][0]["primitives"][0]["attributes"] =
+                    serde_json::json!({ "POSITION": 1 });
+            }
+            let err = read_document(&doc, vec![vec![0; 48]]).unwrap_err();
+            assert!(
+                matches!(&err, ParseReason::Invalid(msg) if msg.starts_with(name)),
+                "{name} {ty} {count}: {err:?}"
+            );
+        }
+    }
+}
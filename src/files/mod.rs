@@ -0,0 +1,370 @@
+// Copyright (C) 2026  Antonio-Miguel Corbi Bellot
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufWriter};
+use std::path::Path;
+
+use crate::types::{Mesh, Point3D};
+
+mod gltf;
+mod obj;
+mod off;
+mod ply;
+mod stl;
+
+pub use gltf::read_gltf;
+pub use obj::{read_obj, write_obj};
+pub use off::read_off;
+pub use ply::{read_ply, write_ply};
+pub use stl::{read_stl, write_stl};
+
+// -- Opciones de escritura: ----------------------------------------------
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PlyEncoding {
+    Ascii,
+    BinaryLittleEndian,
+    BinaryBigEndian,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SaveOptions {
+    // OBJ: qué atributos opcionales se escriben
+    pub normals: bool,
+    pub uvs: bool,
+    pub groups: bool,
+    // STL: ASCII o binario
+    pub stl_ascii: bool,
+    pub ply_encoding: PlyEncoding,
+}
+
+impl Default for SaveOptions {
+    fn default() -> Self {
+        Self {
+            normals: true,
+            uvs: true,
+            groups: true,
+            stl_ascii: false,
+            ply_encoding: PlyEncoding::BinaryLittleEndian,
+        }
+    }
+}
+
+// -- Errores: ------------------------------------------------------------
+// Motivo concreto por el que una línea del fichero no es válida
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseReason {
+    BadFloat(String),
+    BadIndex(String),
+    MissingCoordinate { expected: usize, found: usize },
+    TooFewIndices { expected: usize, found: usize },
+    ZeroIndex,
+    IndexOutOfRange { index: i64, count: usize },
+    UnsupportedDirective(String),
+    UnexpectedToken { expected: String, found: String },
+    UnexpectedEof { expected: String },
+    Truncated { expected: usize, found: usize },
+    Invalid(String),
+    Unsupported(String),
+}
+
+// Error de carga: no se pudo leer el fichero, una línea de un formato de
+// texto es incorrecta (línea y columna empiezan en 1), los datos de un
+// formato binario son incorrectos a partir de cierto byte, el contenido es
+// incoherente sin una posición concreta (p.e. referencias rotas en glTF),
+// o no sabemos leer ese tipo de fichero
+#[derive(Debug)]
+pub enum LoadError {
+    Io {
+        file: String,
+        source: io::Error,
+    },
+    Parse {
+        file: String,
+        line: usize,
+        column: usize,
+        reason: ParseReason,
+    },
+    Corrupt {
+        file: String,
+        offset: usize,
+        reason: ParseReason,
+    },
+    Invalid {
+        file: String,
+        reason: ParseReason,
+    },
+    UnknownFormat {
+        file: String,
+    },
+}
+
+impl fmt::Display for ParseReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseReason::BadFloat(s) => write!(f, "invalid number '{s}'"),
+            ParseReason::BadIndex(s) => write!(f, "invalid index '{s}'"),
+            ParseReason::MissingCoordinate { expected, found } => {
+                write!(f, "expected {expected} coordinates, found {found}")
+            }
+            ParseReason::TooFewIndices { expected, found } => {
+                write!(f, "expected at least {expected} indices, found {found}")
+            }
+            ParseReason::ZeroIndex => write!(f, "indices start at 1, found 0"),
+            ParseReason::IndexOutOfRange { index, count } => {
+                write!(f, "index {index} out of range ({count} elements defined)")
+            }
+            ParseReason::UnsupportedDirective(d) => write!(f, "unsupported directive '{d}'"),
+            ParseReason::UnexpectedToken { expected, found } => {
+                write!(f, "expected '{expected}', found '{found}'")
+            }
+            ParseReason::UnexpectedEof { expected } => {
+                write!(f, "unexpected end of file, expected '{expected}'")
+            }
+            ParseReason::Truncated { expected, found } => {
+                write!(
+                    f,
+                    "truncated data: expected {expected} bytes, found {found}"
+                )
+            }
+            ParseReason::Invalid(msg) => write!(f, "invalid data: {msg}"),
+            ParseReason::Unsupported(what) => write!(f, "unsupported feature: {what}"),
+        }
+    }
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadError::Io { file, source } => write!(f, "{file}: {source}"),
+            LoadError::Parse {
+                file,
+                line,
+                column,
+                reason,
+            } => write!(f, "{file}:{line}:{column}: {reason}"),
+            LoadError::Corrupt {
+                file,
+                offset,
+                reason,
+            } => write!(f, "{file} (byte {offset}): {reason}"),
+            LoadError::Invalid { file, reason } => write!(f, "{file}: {reason}"),
+            LoadError::UnknownFormat { file } => write!(f, "{file}: unknown model format"),
+        }
+    }
+}
+
+impl std::error::Error for LoadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            LoadError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+// -- Utilidades comunes a los lectores de texto: -------------------------
+type ParseResult<T> = Result<T, (usize, ParseReason)>;
+
+// Palabras de la línea junto con su columna (base 1)
+fn tokens(linea: &str) -> Vec<(usize, &str)> {
+    let mut toks = vec![];
+    let mut start = None;
+    for (i, c) in linea.char_indices() {
+        match (c.is_whitespace(), start) {
+            (false, None) => start = Some(i),
+            (true, Some(s)) => {
+                toks.push((s + 1, &linea[s..i]));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(s) = start {
+        toks.push((s + 1, &linea[s..]));
+    }
+    toks
+}
+
+// Lee entre `min` y `max` números reales tras la directiva; los sobrantes
+// (p.e. colores por vértice de algunos exportadores) se ignoran
+fn parse_floats(toks: &[(usize, &str)], min: usize, max: usize) -> ParseResult<Vec<f32>> {
+    let values = &toks[1..];
+    if values.len() < min {
+        let col = toks.last().map_or(1, |(c, t)| c + t.len());
+        return Err((
+            col,
+            ParseReason::MissingCoordinate {
+                expected: min,
+                found: values.len(),
+            },
+        ));
+    }
+    values
+        .iter()
+        .take(max)
+        .map(|&(col, s)| {
+            s.parse()
+                .map_err(|_| (col, ParseReason::BadFloat(s.to_string())))
+        })
+        .collect()
+}
+
+type AsciiResult<T> = Result<T, (usize, usize, ParseReason)>;
+
+// Recorre las palabras del fichero recordando su línea y columna
+// (los comentarios '#' se descartan)
+struct Cursor<'a> {
+    toks: Vec<(usize, usize, &'a str)>,
+    pos: usize,
+    last_line: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(text: &'a str) -> Self {
+        let toks = text
+            .lines()
+            .enumerate()
+            .flat_map(|(n, l)| {
+                let l = l.split('#').next().unwrap_or_default();
+                tokens(l).into_iter().map(move |(c, t)| (n + 1, c, t))
+            })
+            .collect();
+        Self {
+            toks,
+            pos: 0,
+            last_line: text.lines().count().max(1),
+        }
+    }
+
+    fn peek(&self) -> Option<&'a str> {
+        self.toks.get(self.pos).map(|t| t.2)
+    }
+
+    // Línea y columna del siguiente token
+    fn here(&self) -> (usize, usize) {
+        self.toks
+            .get(self.pos)
+            .map_or((self.last_line, 1), |&(line, col, _)| (line, col))
+    }
+
+    fn next(&mut self, expected: &str) -> AsciiResult<(usize, usize, &'a str)> {
+        let tok = self.toks.get(self.pos).copied().ok_or((
+            self.last_line,
+            1,
+            ParseReason::UnexpectedEof {
+                expected: expected.to_string(),
+            },
+        ))?;
+        self.pos += 1;
+        Ok(tok)
+    }
+
+    fn expect(&mut self, keyword: &str) -> AsciiResult<()> {
+        let (line, col, tok) = self.next(keyword)?;
+        if !tok.eq_ignore_ascii_case(keyword) {
+            return Err((
+                line,
+                col,
+                ParseReason::UnexpectedToken {
+                    expected: keyword.to_string(),
+                    found: tok.to_string(),
+                },
+            ));
+        }
+        Ok(())
+    }
+
+    fn float(&mut self) -> AsciiResult<f32> {
+        let (line, col, tok) = self.next("number")?;
+        tok.parse()
+            .map_err(|_| (line, col, ParseReason::BadFloat(tok.to_string())))
+    }
+
+    fn index(&mut self) -> AsciiResult<usize> {
+        let (line, col, tok) = self.next("index")?;
+        tok.parse()
+            .map_err(|_| (line, col, ParseReason::BadIndex(tok.to_string())))
+    }
+
+    fn point(&mut self) -> AsciiResult<Point3D> {
+        Ok(Point3D {
+            x: self.float()?,
+            y: self.float()?,
+            z: self.float()?,
+        })
+    }
+
+    // Tokens que quedan en la línea del último token leído
+    fn rest_of_line(&self) -> usize {
+        let Some(&(line, _, _)) = self.toks.get(self.pos.wrapping_sub(1)) else {
+            return 0;
+        };
+        self.toks[self.pos..]
+            .iter()
+            .take_while(|t| t.0 == line)
+            .count()
+    }
+
+    // Salta el resto de la línea del último token leído
+    fn skip_line(&mut self) {
+        if let Some(&(line, _, _)) = self.toks.get(self.pos.wrapping_sub(1)) {
+            while self.toks.get(self.pos).is_some_and(|t| t.0 == line) {
+                self.pos += 1;
+            }
+        }
+    }
+}
+
+// -- Carga según formato: -----------------------------------------------
+// Carga un modelo como malla lista para el visor, eligiendo el lector por
+// la extensión del fichero
+pub fn load_mesh(fname: &str) -> Result<Mesh, LoadError> {
+    let ext = Path::new(fname)
+        .extension()
+        .map(|e| e.to_string_lossy().to_lowercase());
+    match ext.as_deref() {
+        Some("obj") => read_obj(fname),
+        Some("stl") => read_stl(fname),
+        Some("ply") => read_ply(fname),
+        Some("off") => read_off(fname),
+        Some("gltf" | "glb") => read_gltf(fname),
+        _ => Err(LoadError::UnknownFormat {
+            file: fname.to_string(),
+        }),
+    }
+}
+
+// -- Guardado según formato: ---------------------------------------------
+// Guarda la malla eligiendo el formato por la extensión del fichero
+pub fn save_mesh(fname: &str, mesh: &Mesh, opts: &SaveOptions) -> io::Result<()> {
+    let ext = Path::new(fname)
+        .extension()
+        .map(|e| e.to_string_lossy().to_lowercase());
+    match ext.as_deref() {
+        Some("obj") => write_obj(fname, mesh, opts),
+        Some("stl") => write_stl(fname, mesh, opts.stl_ascii),
+        Some("ply") => write_ply(fname, mesh, opts.ply_encoding),
+        _ => Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "unknown model format (use .obj, .stl or .ply)",
+        )),
+    }
+}
+
+pub fn create(fname: &str) -> io::Result<BufWriter<File>> {
+    Ok(BufWriter::new(File::create(fname)?))
+}
@@ -0,0 +1,605 @@
+// Copyright (C) 2026  Antonio-Miguel Corbi Bellot
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+// Lector y escritor de Wavefront OBJ y de sus bibliotecas de materiales MTL
+
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::Path;
+
+use super::{LoadError, ParseReason, ParseResult, SaveOptions, create, parse_floats, tokens};
+use crate::types::{Corner, Face, Material, Mesh, Point2D, Point3D};
+
+// -- Lectura OBJ: --------------------------------------------------------
+// Directivas que reconocemos pero que, de momento, no usamos
+const IGNORED_DIRECTIVES: &[&str] = &["vp", "p"];
+
+// Convierte un índice OBJ (base 1, o negativo relativo al final) en base 0
+fn resolve_index(s: &str, col: usize, count: usize) -> ParseResult<usize> {
+    let index = s
+        .parse::<i64>()
+        .map_err(|_| (col, ParseReason::BadIndex(s.to_string())))?;
+    let resolved = match index {
+        0 => return Err((col, ParseReason::ZeroIndex)),
+        i if i > 0 => i - 1,
+        i => count as i64 + i,
+    };
+    if resolved < 0 || resolved >= count as i64 {
+        return Err((col, ParseReason::IndexOutOfRange { index, count }));
+    }
+    Ok(resolved as usize)
+}
+
+// Índice opcional de un bloque "v/vt/vn" (vacío = ausente)
+fn resolve_optional(s: Option<&str>, col: usize, count: usize) -> ParseResult<Option<usize>> {
+    match s {
+        None | Some("") => Ok(None),
+        Some(s) => resolve_index(s, col, count).map(Some),
+    }
+}
+
+// Estado del lector mientras recorre el fichero
+#[derive(Default)]
+struct ObjParser {
+    mesh: Mesh,
+    group: Option<usize>,
+    smoothing: u32,
+    material: Option<usize>,
+}
+
+impl ObjParser {
+    fn parse_corner(&self, col: usize, bloque: &str) -> ParseResult<Corner> {
+        let mut parts = bloque.split('/');
+        let v = resolve_index(parts.next().unwrap_or_default(), col, self.mesh.vs.len())?;
+        let vt = resolve_optional(parts.next(), col, self.mesh.uvs.len())?;
+        let vn = resolve_optional(parts.next(), col, self.mesh.ns.len())?;
+        Ok(Corner { v, vt, vn })
+    }
+
+    fn parse_elements(&self, toks: &[(usize, &str)], min: usize) -> ParseResult<Vec<Corner>> {
+        let corners = toks[1..]
+            .iter()
+            .map(|&(col, bloque)| self.parse_corner(col, bloque))
+            .collect::<ParseResult<Vec<_>>>()?;
+        if corners.len() < min {
+            return Err((
+                toks[0].0,
+                ParseReason::TooFewIndices {
+                    expected: min,
+                    found: corners.len(),
+                },
+            ));
+        }
+        Ok(corners)
+    }
+
+    // Los nombres de "o" y "g" definen el grupo de las caras siguientes
+    fn set_group(&mut self, toks: &[(usize, &str)]) {
+        let name = toks[1..]
+            .iter()
+            .map(|(_, s)| *s)
+            .collect::<Vec<_>>()
+            .join(" ");
+        let groups = &mut self.mesh.groups;
+        let idx = groups.iter().position(|g| *g == name).unwrap_or_else(|| {
+            groups.push(name);
+            groups.len() - 1
+        });
+        self.group = Some(idx);
+    }
+
+    fn parse_line(&mut self, toks: &[(usize, &str)]) -> ParseResult<()> {
+        let (dcol, directive) = toks[0];
+        match directive {
+            "v" => {
+                let c = parse_floats(toks, 3, 4)?;
+                if let Some(&w) = c.get(3) {
+                    let ws = &mut self.mesh.ws;
+                    ws.resize(self.mesh.vs.len(), 1.0);
+                    ws.push(w);
+                }
+                self.mesh.vs.push(Point3D {
+                    x: c[0],
+                    y: c[1],
+                    z: c[2],
+                });
+            }
+            "vt" => {
+                let c = parse_floats(toks, 1, 3)?;
+                self.mesh.uvs.push(Point2D {
+                    x: c[0],
+                    y: c.get(1).copied().unwrap_or(0.0),
+                });
+            }
+            "vn" => {
+                let c = parse_floats(toks, 3, 3)?;
+                self.mesh.ns.push(Point3D {
+                    x: c[0],
+                    y: c[1],
+                    z: c[2],
+                });
+            }
+            "f" => {
+                let corners = self.parse_elements(toks, 3)?;
+                self.mesh.fs.push(Face {
+                    corners,
+                    group: self.group,
+                    smoothing: self.smoothing,
+                    material: self.material,
+                });
+            }
+            "l" => {
+                let corners = self.parse_elements(toks, 2)?;
+                self.mesh.ls.push(corners.iter().map(|c| c.v).collect());
+            }
+            "o" | "g" => self.set_group(toks),
+            "usemtl" => {
+                let name = toks.get(1).map_or("", |(_, s)| *s);
+                self.material = Some(self.mesh.material_index(name));
+            }
+            "s" => {
+                self.smoothing = match toks.get(1) {
+                    None | Some((_, "off")) => 0,
+                    Some(&(col, s)) => s
+                        .parse()
+                        .map_err(|_| (col, ParseReason::BadIndex(s.to_string())))?,
+                };
+            }
+            d if IGNORED_DIRECTIVES.contains(&d) => {}
+            d => return Err((dcol, ParseReason::UnsupportedDirective(d.to_string()))),
+        }
+        Ok(())
+    }
+
+    // Carga las bibliotecas de materiales (relativas al directorio del OBJ).
+    // Si una biblioteca no existe se ignora: las caras conservan el material
+    // por nombre con los valores por defecto
+    fn load_mtllibs(&mut self, dir: &Path, toks: &[(usize, &str)]) -> Result<(), LoadError> {
+        for &(_, lib) in &toks[1..] {
+            let path = dir.join(lib);
+            if !path.is_file() {
+                continue;
+            }
+            for m in read_mtl(&path.to_string_lossy())? {
+                let idx = self.mesh.material_index(&m.name);
+                self.mesh.materials[idx] = m;
+            }
+        }
+        Ok(())
+    }
+
+    fn finish(mut self) -> Mesh {
+        if !self.mesh.ws.is_empty() {
+            self.mesh.ws.resize(self.mesh.vs.len(), 1.0);
+        }
+        self.mesh
+    }
+}
+
+// Procesa una línea lógica (ya unida y sin comentarios). `segments` guarda,
+// por cada línea física que la forma, su desplazamiento dentro de `logical`
+// y su número de línea, para situar los errores en el fichero original
+fn parse_logical(
+    parser: &mut ObjParser,
+    path: &Path,
+    fname: &str,
+    segments: &[(usize, usize)],
+    logical: &str,
+) -> Result<(), LoadError> {
+    let toks = tokens(logical);
+    if toks.first().is_some_and(|&(_, d)| d == "mtllib") {
+        let dir = path.parent().unwrap_or(Path::new(""));
+        parser.load_mtllibs(dir, &toks)?;
+    } else if !toks.is_empty() {
+        parser.parse_line(&toks).map_err(|(column, reason)| {
+            let &(start, line) = segments
+                .iter()
+                .rev()
+                .find(|&&(start, _)| start < column)
+                .unwrap_or(&segments[0]);
+            LoadError::Parse {
+                file: fname.to_string(),
+                line,
+                column: column - start,
+                reason,
+            }
+        })?;
+    }
+    Ok(())
+}
+
+pub fn read_obj(fname: &str) -> Result<Mesh, LoadError> {
+    let io_err = |source| LoadError::Io {
+        file: fname.to_string(),
+        source,
+    };
+
+    // 1. Abrir el archivo
+    let path = Path::new(fname);
+    let file = File::open(path).map_err(io_err)?;
+    let reader = BufReader::new(file);
+
+    // 2. Iterar sobre las líneas, uniendo las que acaban en '\'
+    let mut parser = ObjParser::default();
+    let mut logical = String::new();
+    let mut segments = vec![];
+    for (nline, line) in reader.lines().enumerate() {
+        let line = line.map_err(io_err)?; // Manejar posibles errores de lectura
+        segments.push((logical.len(), nline + 1));
+        // Quitar comentarios
+        let line = line.split('#').next().unwrap_or_default();
+        if let Some(cont) = line.trim_end().strip_suffix('\\') {
+            logical.push_str(cont);
+            logical.push(' ');
+            continue;
+        }
+        logical.push_str(line);
+        parse_logical(&mut parser, path, fname, &segments, &logical)?;
+        logical.clear();
+        segments.clear();
+    }
+
+    // 3. Una continuación en la última línea no tiene línea siguiente
+    if !segments.is_empty() {
+        parse_logical(&mut parser, path, fname, &segments, &logical)?;
+    }
+
+    Ok(parser.finish())
+}
+
+// -- Lectura MTL: --------------------------------------------------------
+fn parse_mtl_line(toks: &[(usize, &str)], mats: &mut Vec<Material>) -> ParseResult<()> {
+    let (dcol, directive) = toks[0];
+    if directive == "newmtl" {
+        let name = toks.get(1).map_or("", |(_, s)| *s);
+        mats.push(Material::new(name));
+        return Ok(());
+    }
+    let Some(m) = mats.last_mut() else {
+        return Err((
+            dcol,
+            ParseReason::UnsupportedDirective(directive.to_string()),
+        ));
+    };
+    let rgb = |toks| -> ParseResult<[f32; 3]> {
+        let c = parse_floats(toks, 3, 3)?;
+        Ok([c[0], c[1], c[2]])
+    };
+    match directive {
+        "Ka" => m.ka = rgb(toks)?,
+        "Kd" => m.kd = rgb(toks)?,
+        "Ks" => m.ks = rgb(toks)?,
+        "Ns" => m.ns = parse_floats(toks, 1, 1)?[0],
+        "d" => m.d = parse_floats(toks, 1, 1)?[0],
+        "Tr" => m.d = 1.0 - parse_floats(toks, 1, 1)?[0],
+        // Las opciones (-o, -s, ...) preceden al nombre del fichero
+        "map_Kd" => m.map_kd = toks.last().map(|(_, s)| s.to_string()),
+        // El resto de propiedades (illum, Ni, Ke, map_Bump...) no se usan
+        _ => {}
+    }
+    Ok(())
+}
+
+pub fn read_mtl(fname: &str) -> Result<Vec<Material>, LoadError> {
+    let io_err = |source| LoadError::Io {
+        file: fname.to_string(),
+        source,
+    };
+    let file = File::open(Path::new(fname)).map_err(io_err)?;
+
+    let mut mats = vec![];
+    for (nline, line) in BufReader::new(file).lines().enumerate() {
+        let line = line.map_err(io_err)?;
+        let toks = tokens(line.split('#').next().unwrap_or_default());
+        if toks.is_empty() {
+            continue;
+        }
+        parse_mtl_line(&toks, &mut mats).map_err(|(column, reason)| LoadError::Parse {
+            file: fname.to_string(),
+            line: nline + 1,
+            column,
+            reason,
+        })?;
+    }
+    Ok(mats)
+}
+
+// -- Escritura OBJ/MTL: --------------------------------------------------
+fn write_corner(w: &mut impl Write, c: &Corner, opts: &SaveOptions) -> io::Result<()> {
+    let vt = c.vt.filter(|_| opts.uvs);
+    let vn = c.vn.filter(|_| opts.normals);
+    match (vt, vn) {
+        (None, None) => write!(w, " {}", c.v + 1),
+        (Some(t), None) => write!(w, " {}/{}", c.v + 1, t + 1),
+        (None, Some(n)) => write!(w, " {}//{}", c.v + 1, n + 1),
+        (Some(t), Some(n)) => write!(w, " {}/{}/{}", c.v + 1, t + 1, n + 1),
+    }
+}
+
+// Los materiales van a un .mtl con el mismo nombre que el .obj
+pub fn write_obj(fname: &str, mesh: &Mesh, opts: &SaveOptions) -> io::Result<()> {
+    let mut w = create(fname)?;
+    writeln!(w, "# formulars")?;
+    if !mesh.materials.is_empty() {
+        let mtl = Path::new(fname).with_extension("mtl");
+        write_mtl(&mtl.to_string_lossy(), &mesh.materials)?;
+        if let Some(name) = mtl.file_name() {
+            writeln!(w, "mtllib {}", name.to_string_lossy())?;
+        }
+    }
+
+    for (i, v) in mesh.vs.iter().enumerate() {
+        match mesh.ws.get(i) {
+            Some(&wc) if wc != 1.0 => writeln!(w, "v {} {} {} {}", v.x, v.y, v.z, wc)?,
+            _ => writeln!(w, "v {} {} {}", v.x, v.y, v.z)?,
+        }
+    }
+    if opts.uvs {
+        for t in &mesh.uvs {
+            writeln!(w, "vt {} {}", t.x, t.y)?;
+        }
+    }
+    if opts.normals {
+        for n in &mesh.ns {
+            writeln!(w, "vn {} {} {}", n.x, n.y, n.z)?;
+        }
+    }
+
+    let (mut group, mut smoothing, mut material) = (None, 0, None);
+    for f in &mesh.fs {
+        if opts.groups && f.group != group {
+            group = f.group;
+            let name = group.and_then(|g| mesh.groups.get(g));
+            writeln!(w, "g {}", name.map_or("default", |n| n.as_str()))?;
+        }
+        if opts.groups && f.smoothing != smoothing {
+            smoothing = f.smoothing;
+            match smoothing {
+                0 => writeln!(w, "s off")?,
+                s => writeln!(w, "s {s}")?,
+            }
+        }
+        if f.material != material {
+            material = f.material;
+            if let Some(m) = material.and_then(|m| mesh.materials.get(m)) {
+                writeln!(w, "usemtl {}", m.name)?;
+            }
+        }
+        write!(w, "f")?;
+        for c in &f.corners {
+            write_corner(&mut w, c, opts)?;
+        }
+        writeln!(w)?;
+    }
+
+    for l in &mesh.ls {
+        write!(w, "l")?;
+        for v in l {
+            write!(w, " {}", v + 1)?;
+        }
+        writeln!(w)?;
+    }
+    w.flush()
+}
+
+pub fn write_mtl(fname: &str, mats: &[Material]) -> io::Result<()> {
+    let mut w = create(fname)?;
+    writeln!(w, "# formulars")?;
+    for m in mats {
+        let [r, g, b] = m.ka;
+        writeln!(w, "\nnewmtl {}", m.name)?;
+        writeln!(w, "Ka {r} {g} {b}")?;
+        let [r, g, b] = m.kd;
+        writeln!(w, "Kd {r} {g} {b}")?;
+        let [r, g, b] = m.ks;
+        writeln!(w, "Ks {r} {g} {b}")?;
+        writeln!(w, "Ns {}", m.ns)?;
+        writeln!(w, "d {}", m.d)?;
+        if let Some(map) = &m.map_kd {
+            writeln!(w, "map_Kd {map}")?;
+        }
+    }
+    w.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TempDir;
+
+    #[test]
+    fn obj_round_trip_keeps_attributes() {
+        let tmp = TempDir::new();
+        let src = tmp.file("src.obj");
+        std::fs::write(
+            &src,
+            "v 0 0 0\nv 1 0 0 0.5\nv 1 1 0\nv 0 1 0\nvt 0 0\nvt 1 1\nvn 0 0 1\n\
+             g front\ns 1\nf 1/1/1 2/2/1 3/2/1\ng back\nf 1//1 3//1 4//1\nl 1 2 3\n",
+        )
+        .unwrap();
+        let mesh = read_obj(&src).unwrap();
+
+        let dst = tmp.file("dst.obj");
+        write_obj(&dst, &mesh, &SaveOptions::default()).unwrap();
+        let again = read_obj(&dst).unwrap();
+        assert_eq!(again.fs, mesh.fs);
+        assert_eq!(again.ls, mesh.ls);
+        assert_eq!(again.groups, mesh.groups);
+        assert_eq!(again.ws, mesh.ws);
+        assert_eq!(again.uvs.len(), 2);
+        assert_eq!(again.ns.len(), 1);
+    }
+
+    #[test]
+    fn obj_without_optional_attributes() {
+        let mesh = Mesh::penger();
+        let opts = SaveOptions {
+            normals: false,
+            uvs: false,
+            groups: false,
+            ..SaveOptions::default()
+        };
+        let tmp = TempDir::new();
+        let dst = tmp.file("penger.obj");
+        write_obj(&dst, &mesh, &opts).unwrap();
+        let again = read_obj(&dst).unwrap();
+        assert_eq!(again.fs, mesh.fs);
+        assert_eq!(again.vs.len(), mesh.vs.len());
+        assert!(again.ns.is_empty() && again.groups.is_empty());
+    }
+
+    #[test]
+    fn materials_go_to_a_sibling_mtl() {
+        let mut mesh = Mesh::new(
+            vec![
+                Point3D {
+                    x: 0.0,
+                    y: 0.0,
+                    z: 0.0,
+                },
+                Point3D {
+                    x: 1.0,
+                    y: 0.0,
+                    z: 0.0,
+                },
+                Point3D {
+                    x: 0.0,
+                    y: 1.0,
+                    z: 0.0,
+                },
+            ],
+            vec![vec![0, 1, 2]],
+        );
+        let m = mesh.material_index("red");
+        mesh.materials[m].kd = [1.0, 0.0, 0.0];
+        mesh.fs[0].material = Some(m);
+
+        let tmp = TempDir::new();
+        let dst = tmp.file("mat.obj");
+        write_obj(&dst, &mesh, &SaveOptions::default()).unwrap();
+        let again = read_obj(&dst).unwrap();
+        assert_eq!(again.materials, mesh.materials);
+        assert_eq!(again.fs[0].material, Some(0));
+    }
+
+    #[test]
+    fn obj_parser_directives() {
+        let tmp = TempDir::new();
+        let src = tmp.file("directives.obj");
+        std::fs::write(
+            &src,
+            "# cabecera\nv 0 0 0\nv 1 0 0 2\nv 1 1 0 # comentario\nv 0 1 0\n\
+             vt 0 0\nvt 1 0\nvn 0 0 1\no cubo\ng tapa\ns 3\n\
+             f -4/1/1 -3/2/1 \\\n  -2/2/1\ns off\nf 1//1 3//1 4\nl 1 2 \\\n 3 4\n\
+             g tapa\nf 1 2 3 \\",
+        )
+        .unwrap();
+        let mesh = read_obj(&src).unwrap();
+
+        assert_eq!(mesh.ws, vec![1.0, 2.0, 1.0, 1.0]);
+        assert_eq!(mesh.groups, vec!["cubo".to_string(), "tapa".to_string()]);
+        assert_eq!(mesh.fs.len(), 3);
+        let corner = |v, vt, vn| Corner { v, vt, vn };
+        assert_eq!(
+            mesh.fs[0].corners,
+            vec![
+                corner(0, Some(0), Some(0)),
+                corner(1, Some(1), Some(0)),
+                corner(2, Some(1), Some(0)),
+            ]
+        );
+        assert_eq!((mesh.fs[0].group, mesh.fs[0].smoothing), (Some(1), 3));
+        assert_eq!(
+            mesh.fs[1].corners,
+            vec![
+                corner(0, None, Some(0)),
+                corner(2, None, Some(0)),
+                corner(3, None, None),
+            ]
+        );
+        assert_eq!(mesh.fs[1].smoothing, 0);
+        assert_eq!(mesh.ls, vec![vec![0, 1, 2, 3]]);
+        // La continuación de la última línea también se procesa
+        assert_eq!(mesh.fs[2].group, Some(1));
+        assert_eq!(mesh.fs[2].corners.len(), 3);
+    }
+
+    // Carga `text` y devuelve la línea, columna y motivo del error
+    fn parse_error(name: &str, text: &str) -> (usize, usize, ParseReason) {
+        let tmp = TempDir::new();
+        let src = tmp.file(name);
+        std::fs::write(&src, text).unwrap();
+        match read_obj(&src) {
+            Err(LoadError::Parse {
+                line,
+                column,
+                reason,
+                ..
+            }) => (line, column, reason),
+            other => panic!("expected a parse error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn obj_errors_report_their_position() {
+        assert_eq!(
+            parse_error("float.obj", "v 0 0 0\nv 1 x 0\n"),
+            (2, 5, ParseReason::BadFloat("x".to_string()))
+        );
+        assert_eq!(
+            parse_error("missing.obj", "v 0 0\n"),
+            (
+                1,
+                6,
+                ParseReason::MissingCoordinate {
+                    expected: 3,
+                    found: 2
+                }
+            )
+        );
+        assert_eq!(
+            parse_error("zero.obj", "v 0 0 0\nv 1 0 0\nv 0 1 0\nf 1 0 2\n"),
+            (4, 5, ParseReason::ZeroIndex)
+        );
+        assert_eq!(
+            parse_error("range.obj", "v 0 0 0\nv 1 0 0\nv 0 1 0\n\nf 1 2  4\n"),
+            (5, 8, ParseReason::IndexOutOfRange { index: 4, count: 3 })
+        );
+        assert_eq!(
+            parse_error("negative.obj", "v 0 0 0\nf -1 -2 -3\n"),
+            (
+                2,
+                6,
+                ParseReason::IndexOutOfRange {
+                    index: -2,
+                    count: 1
+                }
+            )
+        );
+        assert_eq!(
+            parse_error("directive.obj", "v 0 0 0\n  curv 0 1\n"),
+            (2, 3, ParseReason::UnsupportedDirective("curv".to_string()))
+        );
+        // En una línea continuada el error se sitúa en su línea física
+        assert_eq!(
+            parse_error("cont.obj", "v 0 0 \\\n y\n"),
+            (2, 2, ParseReason::BadFloat("y".to_string()))
+        );
+        assert_eq!(
+            parse_error("cont2.obj", "v 0 \\\n0 \\\nz\n"),
+            (3, 1, ParseReason::BadFloat("z".to_string()))
+        );
+    }
+}
@@ -0,0 +1,295 @@
+// Copyright (C) 2026  Antonio-Miguel Corbi Bellot
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+// Lector de OFF y sus variantes ([ST][C][N][4]OFF)
+
+use std::fs;
+
+use egui::Color32;
+
+use super::{AsciiResult, Cursor, LoadError, ParseReason};
+use crate::types::{Corner, Face, Mesh, Point2D, Point3D};
+
+// Atributos opcionales que indica el prefijo de la palabra clave
+struct Variant {
+    texture: bool,
+    color: bool,
+    normal: bool,
+    homogeneous: bool,
+}
+
+fn parse_keyword(cur: &mut Cursor) -> AsciiResult<Variant> {
+    let (line, col, kw) = cur.next("OFF")?;
+    let Some(mut prefix) = kw.strip_suffix("OFF") else {
+        return Err((
+            line,
+            col,
+            ParseReason::UnexpectedToken {
+                expected: "OFF".to_string(),
+                found: kw.to_string(),
+            },
+        ));
+    };
+    let mut flag = |p: &str| prefix.strip_prefix(p).map(|rest| prefix = rest).is_some();
+    let variant = Variant {
+        texture: flag("ST"),
+        color: flag("C"),
+        normal: flag("N"),
+        homogeneous: flag("4"),
+    };
+    // "nOFF" (dimensión arbitraria) u otros prefijos no tienen sentido aquí
+    if !prefix.is_empty() {
+        return Err((line, col, ParseReason::UnsupportedDirective(kw.to_string())));
+    }
+    Ok(variant)
+}
+
+// Color RGB con alfa opcional; los enteros van de 0 a 255 y los reales de
+// 0 a 1. `reserved` son los tokens de la línea que siguen al color
+fn read_color(cur: &mut Cursor, reserved: usize) -> AsciiResult<[f32; 4]> {
+    let mut rgba = [1.0f32; 4];
+    let n = if cur.rest_of_line() > 3 + reserved {
+        4
+    } else {
+        3
+    };
+    for c in rgba.iter_mut().take(n) {
+        let is_int = cur.peek().is_some_and(|t| !t.contains(['.', 'e', 'E']));
+        let v = cur.float()?;
+        *c = if is_int { v / 255.0 } else { v };
+    }
+    Ok(rgba)
+}
+
+fn to_color32(rgba: [f32; 4]) -> Color32 {
+    let [r, g, b, a] = rgba.map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u8);
+    Color32::from_rgba_unmultiplied(r, g, b, a)
+}
+
+fn read_off_text(text: &str) -> AsciiResult<Mesh> {
+    let mut cur = Cursor::new(text);
+    let variant = parse_keyword(&mut cur)?;
+    let nv = cur.index()?;
+    let nf = cur.index()?;
+    let _ne = cur.index()?; // Número de aristas: no se usa
+
+    let mut mesh = Mesh::default();
+    for _ in 0..nv {
+        let mut p = cur.point()?;
+        if variant.homogeneous {
+            let w = cur.float()?;
+            if w != 0.0 {
+                p = Point3D {
+                    x: p.x / w,
+                    y: p.y / w,
+                    z: p.z / w,
+                };
+            }
+        }
+        mesh.vs.push(p);
+        if variant.normal {
+            mesh.ns.push(cur.point()?);
+        }
+        if variant.color {
+            let reserved = if variant.texture { 2 } else { 0 };
+            mesh.colors
+                .push(to_color32(read_color(&mut cur, reserved)?));
+        }
+        if variant.texture {
+            mesh.uvs.push(Point2D {
+                x: cur.float()?,
+                y: cur.float()?,
+            });
+        }
+    }
+
+    for _ in 0..nf {
+        let (line, col) = cur.here();
+        let n = cur.index()?;
+        if n < 3 {
+            return Err((
+                line,
+                col,
+                ParseReason::TooFewIndices {
+                    expected: 3,
+                    found: n,
+                },
+            ));
+        }
+        // `n` viene del fichero: no reservamos memoria a partir de él
+        let mut corners = vec![];
+        for _ in 0..n {
+            let (line, col) = cur.here();
+            let v = cur.index()?;
+            if v >= mesh.vs.len() {
+                return Err((
+                    line,
+                    col,
+                    ParseReason::IndexOutOfRange {
+                        index: v as i64,
+                        count: mesh.vs.len(),
+                    },
+                ));
+            }
+            corners.push(Corner {
+                v,
+                vt: variant.texture.then_some(v),
+                vn: variant.normal.then_some(v),
+            });
+        }
+
+        // Color opcional de la cara: lo guardamos como material
+        let mut material = None;
+        if cur.rest_of_line() >= 3 {
+            let rgba = read_color(&mut cur, 0)?;
+            let color = to_color32(rgba);
+            let idx = mesh.material_index(&format!("off_{}", color.to_hex()));
+            let m = &mut mesh.materials[idx];
+            m.kd = [rgba[0], rgba[1], rgba[2]];
+            m.d = rgba[3];
+            material = Some(idx);
+        }
+        cur.skip_line();
+
+        mesh.fs.push(Face {
+            corners,
+            material,
+            ..Face::from_indices(&[])
+        });
+    }
+    Ok(mesh)
+}
+
+pub fn read_off(fname: &str) -> Result<Mesh, LoadError> {
+    let bytes = fs::read(fname).map_err(|source| LoadError::Io {
+        file: fname.to_string(),
+        source,
+    })?;
+    let text = String::from_utf8_lossy(&bytes);
+    read_off_text(&text).map_err(|(line, column, reason)| LoadError::Parse {
+        file: fname.to_string(),
+        line,
+        column,
+        reason,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TempDir;
+
+    fn load(name: &str, text: &str) -> Result<Mesh, LoadError> {
+        let tmp = TempDir::new();
+        let src = tmp.file(name);
+        std::fs::write(&src, text).unwrap();
+        read_off(&src)
+    }
+
+    // Línea, columna y motivo de un error de lectura
+    fn parse_error(name: &str, text: &str) -> (usize, usize, ParseReason) {
+        match load(name, text) {
+            Err(LoadError::Parse {
+                line,
+                column,
+                reason,
+                ..
+            }) => (line, column, reason),
+            other => panic!("expected a parse error, got {other:?}"),
+        }
+    }
+
+    const SQUARE: &str = "0 0 0\n1 0 0\n1 1 0\n0 1 0\n";
+
+    #[test]
+    fn header_counts_and_polygons() {
+        let text = format!(
+            "OFF # cabecera\n# comentario\n\n4 2 0\n{SQUARE}4 0 1 2 3 255 0 0\n3 0 2 3 # fin\n"
+        );
+        let mesh = load("square.off", &text).unwrap();
+        assert_eq!((mesh.vs.len(), mesh.fs.len()), (4, 2));
+        let indices = |f: &Face| f.corners.iter().map(|c| c.v).collect::<Vec<_>>();
+        assert_eq!(indices(&mesh.fs[0]), vec![0, 1, 2, 3]);
+        assert_eq!(indices(&mesh.fs[1]), vec![0, 2, 3]);
+        // El color de la primera cara se guarda como material
+        let m = mesh.fs[0].material.unwrap();
+        assert_eq!(mesh.materials[m].kd, [1.0, 0.0, 0.0]);
+        assert_eq!(mesh.fs[1].material, None);
+    }
+
+    #[test]
+    fn variants_read_their_attributes() {
+        let text = "STCNOFF\n3 1 0\n\
+            0 0 0  0 0 1  1.0 0.5 0.0  0 0\n\
+            1 0 0  0 0 1  255 0 0 128  1 0\n\
+            0 1 0  0 0 1  0 0 255  0 1\n3 0 1 2\n";
+        let mesh = load("variant.off", text).unwrap();
+        assert_eq!(
+            (mesh.ns.len(), mesh.colors.len(), mesh.uvs.len()),
+            (3, 3, 3)
+        );
+        assert_eq!(
+            mesh.colors[1],
+            Color32::from_rgba_unmultiplied(255, 0, 0, 128)
+        );
+        assert_eq!(mesh.uvs[2].y, 1.0);
+        assert_eq!(mesh.fs[0].corners[1].vn, Some(1));
+
+        let mesh = load("homogeneous.off", "4OFF\n1 0 0\n2 4 6 2\n").unwrap();
+        assert_eq!(mesh.vs[0].z, 3.0);
+    }
+
+    #[test]
+    fn bad_indices_are_reported() {
+        let text = format!("OFF\n4 1 0\n{SQUARE}3 0 1  7\n");
+        assert_eq!(
+            parse_error("range.off", &text),
+            (7, 8, ParseReason::IndexOutOfRange { index: 7, count: 4 })
+        );
+        let text = format!("OFF\n4 1 0\n{SQUARE}2 0 1\n");
+        assert_eq!(
+            parse_error("few.off", &text),
+            (
+                7,
+                1,
+                ParseReason::TooFewIndices {
+                    expected: 3,
+                    found: 2
+                }
+            )
+        );
+        assert_eq!(
+            parse_error("keyword.off", "nOFF\n"),
+            (1, 1, ParseReason::UnsupportedDirective("nOFF".to_string()))
+        );
+    }
+
+    #[test]
+    fn truncated_files_are_rejected() {
+        let eof = |expected: &str| ParseReason::UnexpectedEof {
+            expected: expected.to_string(),
+        };
+        // Faltan vértices y caras respecto a la cabecera
+        assert_eq!(
+            parse_error("vertices.off", "OFF\n4 1 0\n0 0 0\n1 0 0\n"),
+            (4, 1, eof("number"))
+        );
+        let text = format!("OFF\n4 2 0\n{SQUARE}3 0 1 2\n");
+        assert_eq!(parse_error("faces.off", &text), (7, 1, eof("index")));
+        // Un número de esquinas desorbitado no reserva memoria
+        let text = format!("OFF\n4 1 0\n{SQUARE}1000000000000000 0 1 2\n");
+        assert_eq!(parse_error("huge.off", &text), (7, 1, eof("index")));
+    }
+}
@@ -0,0 +1,836 @@
+// Copyright (C) 2026  Antonio-Miguel Corbi Bellot
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+// Lector y escritor de PLY (Stanford) en ASCII y binario little/big endian
+
+use std::fs;
+use std::io::{self, Write};
+
+use egui::Color32;
+
+use super::{LoadError, ParseReason, PlyEncoding, create, tokens};
+use crate::types::{Corner, Face, Mesh, Point2D, Point3D};
+
+// -- Cabecera: -----------------------------------------------------------
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Encoding {
+    Ascii,
+    LittleEndian,
+    BigEndian,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Scalar {
+    I8,
+    U8,
+    I16,
+    U16,
+    I32,
+    U32,
+    F32,
+    F64,
+}
+
+#[derive(Debug, Clone)]
+enum PropertyKind {
+    Scalar(Scalar),
+    List { count: Scalar, item: Scalar },
+}
+
+#[derive(Debug, Clone)]
+struct Property {
+    name: String,
+    kind: PropertyKind,
+}
+
+#[derive(Debug, Clone)]
+struct Element {
+    name: String,
+    count: usize,
+    props: Vec<Property>,
+}
+
+#[derive(Debug)]
+struct Header {
+    encoding: Encoding,
+    elements: Vec<Element>,
+    // Byte en que empiezan los datos y línea correspondiente
+    body_offset: usize,
+    body_line: usize,
+}
+
+// Posición de un error: línea/columna en texto o byte en binario
+enum Where {
+    Text(usize, usize),
+    Byte(usize),
+}
+
+type PlyResult<T> = Result<T, (Where, ParseReason)>;
+
+impl Scalar {
+    fn parse(name: &str) -> Option<Self> {
+        Some(match name {
+            "char" | "int8" => Scalar::I8,
+            "uchar" | "uint8" => Scalar::U8,
+            "short" | "int16" => Scalar::I16,
+            "ushort" | "uint16" => Scalar::U16,
+            "int" | "int32" => Scalar::I32,
+            "uint" | "uint32" => Scalar::U32,
+            "float" | "float32" => Scalar::F32,
+            "double" | "float64" => Scalar::F64,
+            _ => return None,
+        })
+    }
+
+    fn size(self) -> usize {
+        match self {
+            Scalar::I8 | Scalar::U8 => 1,
+            Scalar::I16 | Scalar::U16 => 2,
+            Scalar::I32 | Scalar::U32 | Scalar::F32 => 4,
+            Scalar::F64 => 8,
+        }
+    }
+
+    // Valores que admite un tipo entero (los reales no se comprueban)
+    fn range(self) -> std::ops::RangeInclusive<i64> {
+        match self {
+            Scalar::I8 => i8::MIN as i64..=i8::MAX as i64,
+            Scalar::U8 => 0..=u8::MAX as i64,
+            Scalar::I16 => i16::MIN as i64..=i16::MAX as i64,
+            Scalar::U16 => 0..=u16::MAX as i64,
+            Scalar::I32 => i32::MIN as i64..=i32::MAX as i64,
+            Scalar::U32 => 0..=u32::MAX as i64,
+            Scalar::F32 | Scalar::F64 => i64::MIN..=i64::MAX,
+        }
+    }
+
+    fn is_float(self) -> bool {
+        matches!(self, Scalar::F32 | Scalar::F64)
+    }
+}
+
+fn parse_header(bytes: &[u8]) -> PlyResult<Header> {
+    let mut encoding = None;
+    let mut elements: Vec<Element> = vec![];
+    let mut offset = 0;
+
+    for (n, raw) in bytes.split(|&b| b == b'\n').enumerate() {
+        let line = n + 1;
+        offset += raw.len() + 1;
+        let text = String::from_utf8_lossy(raw);
+        let toks = tokens(&text);
+        let err = |col, reason| Err((Where::Text(line, col), reason));
+        let unexpected = |col: usize, expected: &str, found: &str| {
+            err(
+                col,
+                ParseReason::UnexpectedToken {
+                    expected: expected.to_string(),
+                    found: found.to_string(),
+                },
+            )
+        };
+
+        if n == 0 {
+            if text.trim_end() != "ply" {
+                return unexpected(1, "ply", text.trim_end());
+            }
+            continue;
+        }
+        let Some(&(dcol, directive)) = toks.first() else {
+            continue;
+        };
+        let arg = |i: usize, expected: &str| -> PlyResult<(usize, &str)> {
+            toks.get(i).copied().ok_or((
+                Where::Text(line, text.len() + 1),
+                ParseReason::UnexpectedEof {
+                    expected: expected.to_string(),
+                },
+            ))
+        };
+
+        match directive {
+            "format" => {
+                let (col, f) = arg(1, "format")?;
+                encoding = Some(match f {
+                    "ascii" => Encoding::Ascii,
+                    "binary_little_endian" => Encoding::LittleEndian,
+                    "binary_big_endian" => Encoding::BigEndian,
+                    f => return unexpected(col, "ascii|binary_little_endian|binary_big_endian", f),
+                });
+            }
+            "comment" | "obj_info" => {}
+            "element" => {
+                let (_, name) = arg(1, "element name")?;
+                let (col, count) = arg(2, "element count")?;
+                let count = count.parse().map_err(|_| {
+                    (
+                        Where::Text(line, col),
+                        ParseReason::BadIndex(count.to_string()),
+                    )
+                })?;
+                elements.push(Element {
+                    name: name.to_string(),
+                    count,
+                    props: vec![],
+                });
+            }
+            "property" => {
+                let Some(element) = elements.last_mut() else {
+                    return unexpected(dcol, "element", directive);
+                };
+                let scalar = |i: usize| -> PlyResult<Scalar> {
+                    let (col, ty) = arg(i, "property type")?;
+                    Scalar::parse(ty).ok_or((
+                        Where::Text(line, col),
+                        ParseReason::UnexpectedToken {
+                            expected: "property type".to_string(),
+                            found: ty.to_string(),
+                        },
+                    ))
+                };
+                let (kind, name) = if arg(1, "property type")?.1 == "list" {
+                    let kind = PropertyKind::List {
+                        count: scalar(2)?,
+                        item: scalar(3)?,
+                    };
+                    (kind, arg(4, "property name")?.1)
+                } else {
+                    (PropertyKind::Scalar(scalar(1)?), arg(2, "property name")?.1)
+                };
+                element.props.push(Property {
+                    name: name.to_string(),
+                    kind,
+                });
+            }
+            "end_header" => {
+                let Some(encoding) = encoding else {
+                    return unexpected(dcol, "format", directive);
+                };
+                return Ok(Header {
+                    encoding,
+                    elements,
+                    body_offset: offset.min(bytes.len()),
+                    body_line: line + 1,
+                });
+            }
+            d => return err(dcol, ParseReason::UnsupportedDirective(d.to_string())),
+        }
+    }
+
+    Err((
+        Where::Byte(bytes.len()),
+        ParseReason::UnexpectedEof {
+            expected: "end_header".to_string(),
+        },
+    ))
+}
+
+// -- Datos: --------------------------------------------------------------
+// Fuente de valores numéricos, sea texto o binaria
+enum Body<'a> {
+    Text {
+        toks: Vec<(usize, usize, String)>,
+        pos: usize,
+        last_line: usize,
+    },
+    Binary {
+        bytes: &'a [u8],
+        pos: usize,
+        big_endian: bool,
+    },
+}
+
+impl<'a> Body<'a> {
+    fn new(bytes: &'a [u8], header: &Header) -> Self {
+        let data = &bytes[header.body_offset..];
+        match header.encoding {
+            Encoding::Ascii => {
+                let text = String::from_utf8_lossy(data);
+                let toks: Vec<_> = text
+                    .lines()
+                    .enumerate()
+                    .flat_map(|(n, l)| {
+                        tokens(l)
+                            .into_iter()
+                            .map(move |(c, t)| (header.body_line + n, c, t.to_string()))
+                    })
+                    .collect();
+                let last_line = header.body_line + text.lines().count();
+                Body::Text {
+                    toks,
+                    pos: 0,
+                    last_line,
+                }
+            }
+            e => Body::Binary {
+                bytes,
+                pos: header.body_offset,
+                big_endian: e == Encoding::BigEndian,
+            },
+        }
+    }
+
+    fn read(&mut self, ty: Scalar) -> PlyResult<f64> {
+        match self {
+            Body::Text {
+                toks,
+                pos,
+                last_line,
+            } => {
+                let Some((line, col, tok)) = toks.get(*pos) else {
+                    return Err((
+                        Where::Text(*last_line, 1),
+                        ParseReason::UnexpectedEof {
+                            expected: "number".to_string(),
+                        },
+                    ));
+                };
+                *pos += 1;
+                let bad = || {
+                    let reason = if ty.is_float() {
+                        ParseReason::BadFloat(tok.clone())
+                    } else {
+                        ParseReason::BadIndex(tok.clone())
+                    };
+                    (Where::Text(*line, *col), reason)
+                };
+                if ty.is_float() {
+                    tok.parse::<f64>().map_err(|_| bad())
+                } else {
+                    // El entero tiene que caber en el tipo declarado
+                    tok.parse::<i64>()
+                        .ok()
+                        .filter(|&v| ty.range().contains(&v))
+                        .map(|v| v as f64)
+                        .ok_or_else(bad)
+                }
+            }
+            Body::Binary {
+                bytes,
+                pos,
+                big_endian,
+            } => {
+                let size = ty.size();
+                let Some(raw) = bytes.get(*pos..*pos + size) else {
+                    return Err((
+                        Where::Byte(bytes.len()),
+                        ParseReason::Truncated {
+                            expected: *pos + size,
+                            found: bytes.len(),
+                        },
+                    ));
+                };
+                *pos += size;
+                let mut buf = [0u8; 8];
+                buf[..size].copy_from_slice(raw);
+                if *big_endian {
+                    buf[..size].reverse();
+                }
+                Ok(match ty {
+                    Scalar::I8 => buf[0] as i8 as f64,
+                    Scalar::U8 => buf[0] as f64,
+                    Scalar::I16 => i16::from_le_bytes([buf[0], buf[1]]) as f64,
+                    Scalar::U16 => u16::from_le_bytes([buf[0], buf[1]]) as f64,
+                    Scalar::I32 => i32::from_le_bytes(buf[..4].try_into().unwrap()) as f64,
+                    Scalar::U32 => u32::from_le_bytes(buf[..4].try_into().unwrap()) as f64,
+                    Scalar::F32 => f32::from_le_bytes(buf[..4].try_into().unwrap()) as f64,
+                    Scalar::F64 => f64::from_le_bytes(buf),
+                })
+            }
+        }
+    }
+
+    // Posición actual, para informar de errores semánticos
+    fn here(&self) -> Where {
+        match self {
+            Body::Text {
+                toks,
+                pos,
+                last_line,
+            } => toks
+                .get(pos.saturating_sub(1))
+                .map_or(Where::Text(*last_line, 1), |(line, col, _)| {
+                    Where::Text(*line, *col)
+                }),
+            Body::Binary { pos, .. } => Where::Byte(*pos),
+        }
+    }
+}
+
+// Posición de una propiedad escalar en el elemento, junto con su tipo
+fn scalar_prop(element: &Element, name: &str) -> Option<(usize, Scalar)> {
+    element
+        .props
+        .iter()
+        .enumerate()
+        .find_map(|(i, p)| match p.kind {
+            PropertyKind::Scalar(ty) if p.name == name => Some((i, ty)),
+            _ => None,
+        })
+}
+
+// Lee una instancia de `element` sin reservar memoria por instancia: el
+// valor de la propiedad i-ésima queda en `values[i]` (0 si es una lista) y
+// los de la lista `wanted`, si la hay, en `list`. El resto de listas se
+// leen y se descartan
+fn read_item(
+    body: &mut Body,
+    element: &Element,
+    wanted: Option<usize>,
+    values: &mut Vec<f64>,
+    list: &mut Vec<f64>,
+) -> PlyResult<()> {
+    values.clear();
+    list.clear();
+    for (i, p) in element.props.iter().enumerate() {
+        match p.kind {
+            PropertyKind::Scalar(ty) => values.push(body.read(ty)?),
+            PropertyKind::List { count, item: ty } => {
+                let n = body.read(count)?;
+                if n < 0.0 {
+                    return Err((body.here(), ParseReason::BadIndex(n.to_string())));
+                }
+                for _ in 0..n as usize {
+                    let v = body.read(ty)?;
+                    if wanted == Some(i) {
+                        list.push(v);
+                    }
+                }
+                values.push(0.0);
+            }
+        }
+    }
+    Ok(())
+}
+
+fn read_body(body: &mut Body, header: &Header) -> PlyResult<Mesh> {
+    let mut mesh = Mesh::default();
+    let mut values = vec![];
+    let mut list = vec![];
+
+    for element in &header.elements {
+        match element.name.as_str() {
+            "vertex" => {
+                // Los índices de las propiedades se resuelven una sola vez
+                let prop = |name| scalar_prop(element, name);
+                let xyz = [prop("x"), prop("y"), prop("z")];
+                let normal = [prop("nx"), prop("ny"), prop("nz")];
+                let uv = ["s", "u", "texture_u"]
+                    .into_iter()
+                    .zip(["t", "v", "texture_v"])
+                    .find(|(u, _)| prop(u).is_some())
+                    .map(|(u, v)| [prop(u), prop(v)]);
+                let rgba = [prop("red"), prop("green"), prop("blue"), prop("alpha")];
+                let normals = normal[0].is_some();
+                let colors = rgba[0].is_some();
+
+                for _ in 0..element.count {
+                    read_item(body, element, None, &mut values, &mut list)?;
+                    let coord =
+                        |p: Option<(usize, Scalar)>| p.map_or(0.0, |(i, _)| values[i]) as f32;
+                    mesh.vs.push(Point3D {
+                        x: coord(xyz[0]),
+                        y: coord(xyz[1]),
+                        z: coord(xyz[2]),
+                    });
+                    if normals {
+                        mesh.ns.push(Point3D {
+                            x: coord(normal[0]),
+                            y: coord(normal[1]),
+                            z: coord(normal[2]),
+                        });
+                    }
+                    if let Some([u, v]) = uv {
+                        mesh.uvs.push(Point2D {
+                            x: coord(u),
+                            y: coord(v),
+                        });
+                    }
+                    if colors {
+                        // Canal en [0, 255]; los tipos reales vienen en [0, 1]
+                        let c = |p: Option<(usize, Scalar)>| {
+                            p.map_or(255, |(i, ty)| {
+                                let v = if ty.is_float() {
+                                    values[i] * 255.0
+                                } else {
+                                    values[i]
+                                };
+                                v.round().clamp(0.0, 255.0) as u8
+                            })
+                        };
+                        mesh.colors.push(Color32::from_rgba_unmultiplied(
+                            c(rgba[0]),
+                            c(rgba[1]),
+                            c(rgba[2]),
+                            c(rgba[3]),
+                        ));
+                    }
+                }
+            }
+            "face" => {
+                let indices = element.props.iter().position(|p| {
+                    matches!(p.kind, PropertyKind::List { .. })
+                        && (p.name == "vertex_indices" || p.name == "vertex_index")
+                });
+                for _ in 0..element.count {
+                    read_item(body, element, indices, &mut values, &mut list)?;
+                    if indices.is_none() {
+                        continue;
+                    }
+                    if list.len() < 3 {
+                        return Err((
+                            body.here(),
+                            ParseReason::TooFewIndices {
+                                expected: 3,
+                                found: list.len(),
+                            },
+                        ));
+                    }
+                    let mut corners = vec![];
+                    for &i in &list {
+                        if i < 0.0 || i as usize >= mesh.vs.len() {
+                            return Err((
+                                body.here(),
+                                ParseReason::IndexOutOfRange {
+                                    index: i as i64,
+                                    count: mesh.vs.len(),
+                                },
+                            ));
+                        }
+                        let v = i as usize;
+                        corners.push(Corner {
+                            v,
+                            vt: (!mesh.uvs.is_empty()).then_some(v),
+                            vn: (!mesh.ns.is_empty()).then_some(v),
+                        });
+                    }
+                    mesh.fs.push(Face {
+                        corners,
+                        ..Face::from_indices(&[])
+                    });
+                }
+            }
+            // Otros elementos (edge, material...) se leen y se descartan
+            _ => {
+                for _ in 0..element.count {
+                    read_item(body, element, None, &mut values, &mut list)?;
+                }
+            }
+        }
+    }
+    Ok(mesh)
+}
+
+pub fn read_ply(fname: &str) -> Result<Mesh, LoadError> {
+    let bytes = fs::read(fname).map_err(|source| LoadError::Io {
+        file: fname.to_string(),
+        source,
+    })?;
+
+    let parsed = parse_header(&bytes).and_then(|header| {
+        let mut body = Body::new(&bytes, &header);
+        read_body(&mut body, &header)
+    });
+    parsed.map_err(|(at, reason)| match at {
+        Where::Text(line, column) => LoadError::Parse {
+            file: fname.to_string(),
+            line,
+            column,
+            reason,
+        },
+        Where::Byte(offset) => LoadError::Corrupt {
+            file: fname.to_string(),
+            offset,
+            reason,
+        },
+    })
+}
+
+// -- Escritura: ----------------------------------------------------------
+pub fn write_ply(fname: &str, mesh: &Mesh, encoding: PlyEncoding) -> io::Result<()> {
+    let mut w = create(fname)?;
+    let normals = mesh.has_vertex_normals();
+    let uvs = mesh.has_vertex_uvs();
+    let colors = !mesh.vs.is_empty() && mesh.colors.len() == mesh.vs.len();
+    // Caras de más de 255 vértices necesitan un contador más ancho
+    let wide = mesh.fs.iter().any(|f| f.corners.len() > u8::MAX as usize);
+
+    let format = match encoding {
+        PlyEncoding::Ascii => "ascii",
+        PlyEncoding::BinaryLittleEndian => "binary_little_endian",
+        PlyEncoding::BinaryBigEndian => "binary_big_endian",
+    };
+    writeln!(w, "ply\nformat {format} 1.0\ncomment formulars")?;
+    writeln!(w, "element vertex {}", mesh.vs.len())?;
+    let mut props = vec!["x", "y", "z"];
+    if normals {
+        props.extend(["nx", "ny", "nz"]);
+    }
+    if uvs {
+        props.extend(["s", "t"]);
+    }
+    for p in &props {
+        writeln!(w, "property float {p}")?;
+    }
+    if colors {
+        for c in ["red", "green", "blue", "alpha"] {
+            writeln!(w, "property uchar {c}")?;
+        }
+    }
+    writeln!(w, "element face {}", mesh.fs.len())?;
+    let count = if wide { "uint" } else { "uchar" };
+    writeln!(w, "property list {count} int vertex_indices\nend_header")?;
+
+    for (i, v) in mesh.vs.iter().enumerate() {
+        let mut floats = vec![v.x, v.y, v.z];
+        if normals {
+            let n = mesh.ns[i];
+            floats.extend([n.x, n.y, n.z]);
+        }
+        if uvs {
+            floats.extend([mesh.uvs[i].x, mesh.uvs[i].y]);
+        }
+        let rgba = colors.then(|| mesh.colors[i].to_srgba_unmultiplied());
+        match encoding {
+            PlyEncoding::Ascii => {
+                let mut line: Vec<String> = floats.iter().map(|f| f.to_string()).collect();
+                line.extend(rgba.iter().flatten().map(|c| c.to_string()));
+                writeln!(w, "{}", line.join(" "))?;
+            }
+            PlyEncoding::BinaryLittleEndian => {
+                for f in floats {
+                    w.write_all(&f.to_le_bytes())?;
+                }
+                w.write_all(rgba.as_ref().map_or(&[][..], |c| &c[..]))?;
+            }
+            PlyEncoding::BinaryBigEndian => {
+                for f in floats {
+                    w.write_all(&f.to_be_bytes())?;
+                }
+                w.write_all(rgba.as_ref().map_or(&[][..], |c| &c[..]))?;
+            }
+        }
+    }
+
+    for f in &mesh.fs {
+        let n = f.corners.len() as u32;
+        match encoding {
+            PlyEncoding::Ascii => {
+                write!(w, "{n}")?;
+                for c in &f.corners {
+                    write!(w, " {}", c.v)?;
+                }
+                writeln!(w)?;
+            }
+            PlyEncoding::BinaryLittleEndian | PlyEncoding::BinaryBigEndian => {
+                let be = encoding == PlyEncoding::BinaryBigEndian;
+                let int = |v: u32| if be { v.to_be_bytes() } else { v.to_le_bytes() };
+                if wide {
+                    w.write_all(&int(n))?;
+                } else {
+                    w.write_all(&[n as u8])?;
+                }
+                for c in &f.corners {
+                    w.write_all(&int(c.v as u32))?;
+                }
+            }
+        }
+    }
+    w.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TempDir;
+
+    #[test]
+    fn round_trip_in_every_encoding() {
+        let mut mesh = Mesh::penger();
+        mesh.colors = (0..mesh.vs.len())
+            .map(|i| Color32::from_rgb(i as u8, 0, 255 - i as u8))
+            .collect();
+        mesh.ns = mesh.vs.clone();
+        for f in &mut mesh.fs {
+            for c in &mut f.corners {
+                c.vn = Some(c.v);
+            }
+        }
+
+        let tmp = TempDir::new();
+        for (name, enc) in [
+            ("ascii.ply", PlyEncoding::Ascii),
+            ("le.ply", PlyEncoding::BinaryLittleEndian),
+            ("be.ply", PlyEncoding::BinaryBigEndian),
+        ] {
+            let dst = tmp.file(name);
+            write_ply(&dst, &mesh, enc).unwrap();
+            let again = read_ply(&dst).unwrap();
+            assert_eq!(again.fs, mesh.fs, "{name}");
+            assert_eq!(again.colors, mesh.colors, "{name}");
+            assert_eq!(again.vs.len(), mesh.vs.len(), "{name}");
+            assert_eq!(again.vs[10].x, mesh.vs[10].x, "{name}");
+            assert_eq!(again.ns[10].z, mesh.ns[10].z, "{name}");
+        }
+    }
+
+    fn load(name: &str, bytes: &[u8]) -> Result<Mesh, LoadError> {
+        let tmp = TempDir::new();
+        let src = tmp.file(name);
+        std::fs::write(&src, bytes).unwrap();
+        read_ply(&src)
+    }
+
+    // Línea, columna y motivo de un error en la parte de texto
+    fn parse_error(name: &str, text: &str) -> (usize, usize, ParseReason) {
+        match load(name, text.as_bytes()) {
+            Err(LoadError::Parse {
+                line,
+                column,
+                reason,
+                ..
+            }) => (line, column, reason),
+            other => panic!("expected a parse error, got {other:?}"),
+        }
+    }
+
+    fn unexpected(expected: &str, found: &str) -> ParseReason {
+        ParseReason::UnexpectedToken {
+            expected: expected.to_string(),
+            found: found.to_string(),
+        }
+    }
+
+    const TRIANGLE: &str = "element vertex 3\nproperty float x\nproperty float y\n\
+        property float z\nelement face 1\nproperty list uchar int vertex_indices\nend_header\n";
+
+    #[test]
+    fn ascii_body_with_extra_properties() {
+        let text = "ply\nformat ascii 1.0\ncomment hecho a mano\n\
+            element vertex 3\nproperty float x\nproperty float y\nproperty float z\n\
+            property float quality\nproperty uchar red\nproperty uchar green\nproperty uchar blue\n\
+            element face 1\nproperty uchar flags\nproperty list uchar int vertex_indices\n\
+            element edge 1\nproperty int vertex1\nproperty int vertex2\nend_header\n\
+            0 0 0 0.5 255 0 0\n1 0 0 0.5 0 255 0\n0 1 2 0.5 0 0 255\n7 3 2 1 0\n0 1\n";
+        let mesh = load("extra.ply", text.as_bytes()).unwrap();
+        assert_eq!(mesh.vs[2].z, 2.0);
+        assert_eq!(mesh.colors[1], Color32::from_rgb(0, 255, 0));
+        let indices: Vec<_> = mesh.fs[0].corners.iter().map(|c| c.v).collect();
+        assert_eq!(indices, vec![2, 1, 0]);
+    }
+
+    #[test]
+    fn header_errors_report_their_position() {
+        assert_eq!(
+            parse_error("magic.ply", "plx\n"),
+            (1, 1, unexpected("ply", "plx"))
+        );
+        assert_eq!(
+            parse_error("format.ply", "ply\nformat binary 1.0\n"),
+            (
+                2,
+                8,
+                unexpected("ascii|binary_little_endian|binary_big_endian", "binary")
+            )
+        );
+        assert_eq!(
+            parse_error("noformat.ply", "ply\nformat\n"),
+            (
+                2,
+                7,
+                ParseReason::UnexpectedEof {
+                    expected: "format".to_string()
+                }
+            )
+        );
+        assert_eq!(
+            parse_error(
+                "type.ply",
+                "ply\nformat ascii 1.0\nelement vertex 1\nproperty vec3 x\n"
+            ),
+            (4, 10, unexpected("property type", "vec3"))
+        );
+        assert_eq!(
+            parse_error(
+                "listtype.ply",
+                "ply\nformat ascii 1.0\nelement face 1\nproperty list uchar index v\n"
+            ),
+            (4, 21, unexpected("property type", "index"))
+        );
+        assert_eq!(
+            parse_error("orphan.ply", "ply\nformat ascii 1.0\nproperty float x\n"),
+            (3, 1, unexpected("element", "property"))
+        );
+        assert!(matches!(
+            load("eof.ply", b"ply\nformat ascii 1.0\n"),
+            Err(LoadError::Corrupt {
+                reason: ParseReason::UnexpectedEof { .. },
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn truncated_binary_body() {
+        let header = format!("ply\nformat binary_little_endian 1.0\n{TRIANGLE}");
+        let mut bytes = header.clone().into_bytes();
+        for c in [0.0f32, 0.0, 0.0, 1.0, 0.0, 0.0] {
+            bytes.extend(c.to_le_bytes());
+        }
+        // Falta el tercer vértice entero
+        bytes.extend(0.0f32.to_le_bytes());
+        match load("short.ply", &bytes) {
+            Err(LoadError::Corrupt { offset, reason, .. }) => {
+                assert_eq!(offset, bytes.len());
+                assert_eq!(
+                    reason,
+                    ParseReason::Truncated {
+                        expected: header.len() + 32,
+                        found: bytes.len()
+                    }
+                );
+            }
+            other => panic!("expected a corrupt file, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn list_counts_must_fit_their_type_and_the_data() {
+        // En texto, el contador "uchar" no puede pasar de 255
+        let text = format!("ply\nformat ascii 1.0\n{TRIANGLE}0 0 0\n1 0 0\n0 1 0\n300 0 1 2\n");
+        assert_eq!(
+            parse_error("count.ply", &text),
+            (13, 1, ParseReason::BadIndex("300".to_string()))
+        );
+
+        // En binario, un contador enorme se queda sin datos sin reservar
+        // memoria para él
+        let header = format!("ply\nformat binary_big_endian 1.0\n{TRIANGLE}")
+            .replace("list uchar", "list uint");
+        let mut bytes = header.into_bytes();
+        for c in [0.0f32, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0] {
+            bytes.extend(c.to_be_bytes());
+        }
+        bytes.extend(u32::MAX.to_be_bytes());
+        bytes.extend([0, 1, 2].iter().flat_map(|i: &i32| i.to_be_bytes()));
+        assert!(matches!(
+            load("overflow.ply", &bytes),
+            Err(LoadError::Corrupt {
+                reason: ParseReason::Truncated { .. },
+                ..
+            })
+        ));
+    }
+}
@@ -0,0 +1,411 @@
+// Copyright (C) 2026  Antonio-Miguel Corbi Bellot
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+// Lector y escritor de STL en sus dos variantes, ASCII y binaria
+
+use std::collections::HashMap;
+use std::fs;
+use std::io::{self, Write};
+
+use super::{AsciiResult, Cursor, LoadError, ParseReason, create};
+use crate::types::{Corner, Face, Mesh, Point3D};
+
+const HEADER_LEN: usize = 80;
+const TRIANGLE_LEN: usize = 50;
+
+// -- Soldado de vértices: ------------------------------------------------
+// STL repite los vértices en cada triángulo; los unimos en índices
+// compartidos para obtener la misma malla de caras que el lector OBJ
+#[derive(Default)]
+struct Welder {
+    mesh: Mesh,
+    index: HashMap<[u32; 3], usize>,
+}
+
+impl Welder {
+    fn vertex(&mut self, p: Point3D) -> usize {
+        // Sumar 0.0 convierte -0.0 en 0.0 para que ambos se suelden
+        let key = [p.x + 0.0, p.y + 0.0, p.z + 0.0].map(f32::to_bits);
+        let vs = &mut self.mesh.vs;
+        *self.index.entry(key).or_insert_with(|| {
+            vs.push(p);
+            vs.len() - 1
+        })
+    }
+
+    // Añade una faceta conservando su normal
+    fn facet(&mut self, normal: Point3D, vertices: &[Point3D]) {
+        self.mesh.ns.push(normal);
+        let vn = Some(self.mesh.ns.len() - 1);
+        let corners = vertices
+            .iter()
+            .map(|&p| Corner {
+                v: self.vertex(p),
+                vt: None,
+                vn,
+            })
+            .collect();
+        self.mesh.fs.push(Face {
+            corners,
+            ..Face::from_indices(&[])
+        });
+    }
+}
+
+// -- Detección del formato: ----------------------------------------------
+// Un STL binario tiene exactamente 84 + 50·n bytes. Algunos exportadores
+// escriben "solid" también en la cabecera binaria, así que el tamaño manda;
+// si no cuadra (fichero truncado), un byte nulo delata que no es texto
+fn is_binary(bytes: &[u8]) -> bool {
+    if bytes.len() >= HEADER_LEN + 4 {
+        let n = u32::from_le_bytes(bytes[HEADER_LEN..HEADER_LEN + 4].try_into().unwrap());
+        if bytes.len() == HEADER_LEN + 4 + n as usize * TRIANGLE_LEN {
+            return true;
+        }
+    }
+    !bytes.trim_ascii_start().starts_with(b"solid") || bytes.contains(&0)
+}
+
+pub fn read_stl(fname: &str) -> Result<Mesh, LoadError> {
+    let bytes = fs::read(fname).map_err(|source| LoadError::Io {
+        file: fname.to_string(),
+        source,
+    })?;
+
+    if is_binary(&bytes) {
+        read_binary(&bytes).map_err(|(offset, reason)| LoadError::Corrupt {
+            file: fname.to_string(),
+            offset,
+            reason,
+        })
+    } else {
+        let text = String::from_utf8_lossy(&bytes);
+        read_ascii(&text).map_err(|(line, column, reason)| LoadError::Parse {
+            file: fname.to_string(),
+            line,
+            column,
+            reason,
+        })
+    }
+}
+
+// -- STL binario: --------------------------------------------------------
+fn read_binary(bytes: &[u8]) -> Result<Mesh, (usize, ParseReason)> {
+    let truncated = |expected| {
+        (
+            bytes.len(),
+            ParseReason::Truncated {
+                expected,
+                found: bytes.len(),
+            },
+        )
+    };
+    if bytes.len() < HEADER_LEN + 4 {
+        return Err(truncated(HEADER_LEN + 4));
+    }
+    let n = u32::from_le_bytes(bytes[HEADER_LEN..HEADER_LEN + 4].try_into().unwrap()) as usize;
+    let expected = HEADER_LEN + 4 + n * TRIANGLE_LEN;
+    if bytes.len() < expected {
+        return Err(truncated(expected));
+    }
+
+    let mut welder = Welder::default();
+    for t in 0..n {
+        let start = HEADER_LEN + 4 + t * TRIANGLE_LEN;
+        // normal + 3 vértices, 3 floats cada uno; los 2 bytes finales son
+        // el "attribute byte count", que no usamos
+        let mut ps = [Point3D {
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+        }; 4];
+        for (i, p) in ps.iter_mut().enumerate() {
+            let mut xyz = [0.0f32; 3];
+            for (j, c) in xyz.iter_mut().enumerate() {
+                let off = start + (i * 3 + j) * 4;
+                *c = f32::from_le_bytes(bytes[off..off + 4].try_into().unwrap());
+                if !c.is_finite() {
+                    return Err((off, ParseReason::BadFloat(c.to_string())));
+                }
+            }
+            *p = Point3D {
+                x: xyz[0],
+                y: xyz[1],
+                z: xyz[2],
+            };
+        }
+        welder.facet(ps[0], &ps[1..]);
+    }
+    Ok(welder.mesh)
+}
+
+// -- STL ASCII: ----------------------------------------------------------
+fn read_ascii(text: &str) -> AsciiResult<Mesh> {
+    let mut cur = Cursor::new(text);
+    let mut welder = Welder::default();
+
+    // Puede haber varios sólidos seguidos en el mismo fichero
+    loop {
+        cur.expect("solid")?;
+        cur.skip_line();
+        while cur.peek().is_some_and(|t| t.eq_ignore_ascii_case("facet")) {
+            cur.expect("facet")?;
+            cur.expect("normal")?;
+            let normal = cur.point()?;
+            cur.expect("outer")?;
+            cur.expect("loop")?;
+            let mut vertices = vec![];
+            while cur.peek().is_some_and(|t| t.eq_ignore_ascii_case("vertex")) {
+                cur.expect("vertex")?;
+                vertices.push(cur.point()?);
+            }
+            if vertices.len() < 3 {
+                let (line, col, _) = cur.next("vertex")?;
+                return Err((
+                    line,
+                    col,
+                    ParseReason::TooFewIndices {
+                        expected: 3,
+                        found: vertices.len(),
+                    },
+                ));
+            }
+            cur.expect("endloop")?;
+            cur.expect("endfacet")?;
+            welder.facet(normal, &vertices);
+        }
+        cur.expect("endsolid")?;
+        cur.skip_line();
+        if cur.peek().is_none() {
+            return Ok(welder.mesh);
+        }
+    }
+}
+
+// -- Escritura: ----------------------------------------------------------
+// Triángulos (en abanico) con su normal: la de la faceta si todas las
+// esquinas comparten la misma, o la calculada a partir de la geometría
+fn triangles(mesh: &Mesh) -> Vec<(Point3D, [Point3D; 3])> {
+    let mut tris = vec![];
+    for f in &mesh.fs {
+        let shared = f
+            .corners
+            .first()
+            .and_then(|c| c.vn)
+            .filter(|&n| n < mesh.ns.len() && f.corners.iter().all(|c| c.vn == Some(n)));
+        let normal = shared.map_or_else(|| mesh.face_normal(f), |n| mesh.ns[n]);
+        for i in 1..f.corners.len().saturating_sub(1) {
+            let v = |k: usize| mesh.vs[f.corners[k].v];
+            tris.push((normal, [v(0), v(i), v(i + 1)]));
+        }
+    }
+    tris
+}
+
+pub fn write_stl(fname: &str, mesh: &Mesh, ascii: bool) -> io::Result<()> {
+    let mut w = create(fname)?;
+    let tris = triangles(mesh);
+    if ascii {
+        writeln!(w, "solid formulars")?;
+        for (n, vs) in &tris {
+            writeln!(w, "  facet normal {} {} {}", n.x, n.y, n.z)?;
+            writeln!(w, "    outer loop")?;
+            for v in vs {
+                writeln!(w, "      vertex {} {} {}", v.x, v.y, v.z)?;
+            }
+            writeln!(w, "    endloop")?;
+            writeln!(w, "  endfacet")?;
+        }
+        writeln!(w, "endsolid formulars")?;
+    } else {
+        let mut header = [b' '; HEADER_LEN];
+        header[..9].copy_from_slice(b"formulars");
+        w.write_all(&header)?;
+        w.write_all(&(tris.len() as u32).to_le_bytes())?;
+        for (n, vs) in &tris {
+            for p in std::iter::once(n).chain(vs) {
+                for c in [p.x, p.y, p.z] {
+                    w.write_all(&c.to_le_bytes())?;
+                }
+            }
+            w.write_all(&0u16.to_le_bytes())?;
+        }
+    }
+    w.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TempDir;
+
+    fn indices(mesh: &Mesh) -> Vec<Vec<usize>> {
+        mesh.fs
+            .iter()
+            .map(|f| f.corners.iter().map(|c| c.v).collect())
+            .collect()
+    }
+
+    // Posiciones de las esquinas de cada cara (independiente de cómo se
+    // numeren los vértices al soldarlos)
+    fn corners(mesh: &Mesh) -> Vec<[f32; 3]> {
+        mesh.fs
+            .iter()
+            .flat_map(|f| &f.corners)
+            .map(|c| {
+                let p = mesh.vs[c.v];
+                [p.x, p.y, p.z]
+            })
+            .collect()
+    }
+
+    #[test]
+    fn binary_and_ascii_round_trip() {
+        let mesh = Mesh::penger();
+        let tmp = TempDir::new();
+        for ascii in [false, true] {
+            let dst = tmp.file(if ascii { "ascii.stl" } else { "binary.stl" });
+            write_stl(&dst, &mesh, ascii).unwrap();
+            let again = read_stl(&dst).unwrap();
+            assert_eq!(again.vs.len(), mesh.vs.len());
+            assert_eq!(again.fs.len(), mesh.fs.len());
+            assert_eq!(corners(&again), corners(&mesh));
+            assert_eq!(again.ns.len(), mesh.fs.len());
+        }
+    }
+
+    #[test]
+    fn facet_normals_are_kept_and_quads_split() {
+        let p = |x, y| Point3D { x, y, z: 0.0 };
+        let mut mesh = Mesh::new(
+            vec![p(0.0, 0.0), p(1.0, 0.0), p(1.0, 1.0), p(0.0, 1.0)],
+            vec![vec![0, 1, 2, 3]],
+        );
+        let tmp = TempDir::new();
+        let dst = tmp.file("quad.stl");
+        write_stl(&dst, &mesh, false).unwrap();
+        let again = read_stl(&dst).unwrap();
+        assert_eq!(indices(&again), vec![vec![0, 1, 2], vec![0, 2, 3]]);
+        assert_eq!(again.ns[0].z, 1.0);
+
+        // Una normal de faceta explícita se respeta
+        mesh.ns.push(Point3D {
+            x: 0.0,
+            y: 0.0,
+            z: -1.0,
+        });
+        mesh.fs[0].corners.iter_mut().for_each(|c| c.vn = Some(0));
+        write_stl(&dst, &mesh, true).unwrap();
+        assert_eq!(read_stl(&dst).unwrap().ns[1].z, -1.0);
+    }
+
+    // STL binario con `count` en la cabecera y `tris` triángulos detrás
+    fn binary(header: &[u8], count: u32, tris: usize) -> Vec<u8> {
+        let mut bytes = vec![b' '; HEADER_LEN];
+        bytes[..header.len()].copy_from_slice(header);
+        bytes.extend(count.to_le_bytes());
+        for _ in 0..tris {
+            for c in [
+                0.0f32, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0,
+            ] {
+                bytes.extend(c.to_le_bytes());
+            }
+            bytes.extend([0, 0]);
+        }
+        bytes
+    }
+
+    fn load(name: &str, bytes: &[u8]) -> Result<Mesh, LoadError> {
+        let tmp = TempDir::new();
+        let src = tmp.file(name);
+        std::fs::write(&src, bytes).unwrap();
+        read_stl(&src)
+    }
+
+    #[test]
+    fn ascii_and_binary_are_detected() {
+        let ascii = b"solid t\nfacet normal 0 0 1\nouter loop\n\
+            vertex 0 0 0\nvertex 1 0 0\nvertex 0 1 0\nendloop\nendfacet\nendsolid t\n";
+        assert!(!is_binary(ascii));
+        assert_eq!(load("detect.stl", ascii).unwrap().fs.len(), 1);
+
+        // Una cabecera binaria que empieza por "solid" manda el tamaño
+        let bytes = binary(b"solid exported", 2, 2);
+        assert!(is_binary(&bytes));
+        let mesh = load("solid.stl", &bytes).unwrap();
+        assert_eq!((mesh.fs.len(), mesh.vs.len()), (2, 3));
+
+        // Si el tamaño no cuadra, un byte nulo delata que no es texto
+        let mut bytes = binary(b"solid", 1, 1);
+        bytes.push(0);
+        assert!(is_binary(&bytes));
+    }
+
+    #[test]
+    fn binary_count_must_fit_the_length() {
+        // La cabecera anuncia 3 triángulos pero solo hay 1
+        let bytes = binary(b"formulars", 3, 1);
+        match load("count.stl", &bytes) {
+            Err(LoadError::Corrupt { offset, reason, .. }) => {
+                assert_eq!(offset, bytes.len());
+                assert_eq!(
+                    reason,
+                    ParseReason::Truncated {
+                        expected: HEADER_LEN + 4 + 3 * TRIANGLE_LEN,
+                        found: bytes.len()
+                    }
+                );
+            }
+            other => panic!("expected a corrupt file, got {other:?}"),
+        }
+
+        // Los bytes sobrantes tras los triángulos anunciados se ignoran
+        let mesh = load("extra.stl", &binary(b"formulars", 1, 2)).unwrap();
+        assert_eq!(mesh.fs.len(), 1);
+    }
+
+    #[test]
+    fn truncated_files_are_rejected() {
+        assert!(matches!(
+            load("short.stl", b"formulars"),
+            Err(LoadError::Corrupt {
+                reason: ParseReason::Truncated {
+                    expected: 84,
+                    found: 9
+                },
+                ..
+            })
+        ));
+
+        let text = b"solid t\nfacet normal 0 0 1\nouter loop\nvertex 0 0 0\nvertex 1 0 0\n";
+        match load("short-ascii.stl", text) {
+            Err(LoadError::Parse {
+                line,
+                column,
+                reason,
+                ..
+            }) => {
+                assert_eq!((line, column), (5, 1));
+                assert_eq!(
+                    reason,
+                    ParseReason::UnexpectedEof {
+                        expected: "vertex".to_string()
+                    }
+                );
+            }
+            other => panic!("expected a parse error, got {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,259 @@
+// Copyright (C) 2026  Antonio-Miguel Corbi Bellot
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+// Órdenes que no necesitan ventana: stats, convert y render
+
+use std::collections::HashSet;
+
+use egui::{Rect, pos2, vec2};
+
+use crate::anim::{self, AnimOptions};
+use crate::camera::{Camera, FIT_RADIUS};
+use crate::cli::{AnimateArgs, Command, LookArgs, RenderArgs, RotateAxis};
+use crate::files::{self, PlyEncoding, SaveOptions};
+use crate::math::Vec3;
+use crate::raster::Canvas;
+use crate::render::{self, Light, View};
+use crate::types::{Axe, Mesh, Point3D};
+
+// Lado máximo de las imágenes: evita reservar lienzos de gigas por error
+const MAX_IMAGE_SIDE: f32 = 16384.0;
+
+pub fn run(command: Command) -> Result<(), String> {
+    match command {
+        Command::Stats { model } => stats(&model),
+        Command::Convert {
+            input,
+            output,
+            ascii,
+        } => convert(&input, &output, ascii),
+        Command::Render(args) => render(&args),
+        Command::Animate(args) => animate(&args),
+    }
+}
+
+// -- stats: --------------------------------------------------------------
+fn stats(fname: &str) -> Result<(), String> {
+    let mesh = files::load_mesh(fname).map_err(|e| e.to_string())?;
+    let triangles: usize = mesh
+        .fs
+        .iter()
+        .map(|f| f.corners.len().saturating_sub(2))
+        .sum();
+    // Aristas sin repetir: la compartida por dos caras cuenta una vez
+    let edges: HashSet<(usize, usize)> = mesh
+        .segments()
+        .map(|(a, b, _)| (a.min(b), a.max(b)))
+        .collect();
+
+    println!("{fname}");
+    println!("  vertices:  {}", mesh.vs.len());
+    println!("  faces:     {} ({triangles} triangles)", mesh.fs.len());
+    println!("  lines:     {}", mesh.ls.len());
+    println!("  edges:     {}", edges.len());
+    println!("  normals:   {}", mesh.ns.len());
+    println!("  uvs:       {}", mesh.uvs.len());
+    println!("  groups:    {}", mesh.groups.len());
+    println!("  materials: {}", mesh.materials.len());
+    if let Some((lo, hi)) = mesh.bounds() {
+        let fmt = |p: Point3D| format!("({}, {}, {})", p.x, p.y, p.z);
+        let size = Point3D {
+            x: hi.x - lo.x,
+            y: hi.y - lo.y,
+            z: hi.z - lo.z,
+        };
+        println!("  bounds:    {} .. {}", fmt(lo), fmt(hi));
+        println!("  size:      {}", fmt(size));
+    }
+    Ok(())
+}
+
+// -- convert: ------------------------------------------------------------
+fn convert(input: &str, output: &str, ascii: bool) -> Result<(), String> {
+    let mesh = files::load_mesh(input).map_err(|e| e.to_string())?;
+    let opts = SaveOptions {
+        stl_ascii: ascii,
+        ply_encoding: if ascii {
+            PlyEncoding::Ascii
+        } else {
+            PlyEncoding::BinaryLittleEndian
+        },
+        ..SaveOptions::default()
+    };
+    files::save_mesh(output, &mesh, &opts).map_err(|e| format!("{output}: {e}"))
+}
+
+// -- render: -------------------------------------------------------------
+fn image_size(look: &LookArgs) -> Result<(usize, usize), String> {
+    let [w, h] = look.size;
+    if w > MAX_IMAGE_SIDE || h > MAX_IMAGE_SIDE {
+        return Err(format!(
+            "image size {w}x{h} is too large (at most {MAX_IMAGE_SIDE}x{MAX_IMAGE_SIDE})"
+        ));
+    }
+    Ok((w as usize, h as usize))
+}
+
+fn render(args: &RenderArgs) -> Result<(), String> {
+    image_size(&args.look)?;
+    let mesh = files::load_mesh(&args.model).map_err(|e| e.to_string())?;
+    let [w, h] = args.look.size;
+    let screenr = Rect::from_min_size(pos2(0.0, 0.0), vec2(w, h));
+    let mut canvas = Canvas::new(w as usize, h as usize, args.look.background);
+    canvas.draw(&render::build(
+        &mesh,
+        &view(&mesh, &args.look, args.angle),
+        screenr,
+    ));
+    canvas
+        .write_png(&args.output)
+        .map_err(|e| format!("{}: {e}", args.output))
+}
+
+// -- animate: ------------------------------------------------------------
+fn animate(args: &AnimateArgs) -> Result<(), String> {
+    let (width, height) = image_size(&args.look)?;
+    let mesh = files::load_mesh(&args.model).map_err(|e| e.to_string())?;
+    let (frames, step) = AnimOptions::turn(args.frames, args.step);
+    let opts = AnimOptions {
+        width,
+        height,
+        frames,
+        step,
+        fps: args.fps,
+        loops: args.loops,
+        background: args.look.background,
+    };
+    let view = view(&mesh, &args.look, 0.0);
+    let axes: Vec<Axe> = view.rotation.iter().map(|&(axe, _)| axe).collect();
+    let written = anim::record(&args.output, &mesh, &view, &axes, &opts)
+        .map_err(|e| format!("{}: {e}", args.output))?;
+    println!("{frames} frames, {written} file(s) written");
+    Ok(())
+}
+
+// Misma vista que el visor: modelo encajado, giro en los ejes pedidos y
+// cámara a la distancia que marca el zoom
+fn view(mesh: &Mesh, look: &LookArgs, angle: f32) -> View {
+    let (center, scale) = mesh.fit(FIT_RADIUS);
+    // Con zoom 1 (o sin zoom) el modelo llena la imagen
+    let [w, h] = look.size;
+    let distance = Camera::default().fit_distance(FIT_RADIUS, w / h) / look.zoom.unwrap_or(1.0);
+    let rotation = look
+        .axis
+        .iter()
+        .filter_map(|a| match a {
+            RotateAxis::X => Some(Axe::X),
+            RotateAxis::Y => Some(Axe::Y),
+            RotateAxis::Z => Some(Axe::Z),
+            RotateAxis::None => None,
+        })
+        .map(|axe| (axe, angle))
+        .collect();
+    View {
+        center,
+        scale,
+        rotation,
+        camera: Camera::looking_at(Vec3::ZERO, distance),
+        draw_vs: look.points,
+        draw_fs: look.wireframe || !(look.points || look.solid),
+        draw_bbox: look.bbox,
+        solid: look.solid,
+        light: Light::default(),
+        line_width: 1.0,
+        point_radius: 1.5,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::Cli;
+    use crate::test_utils::TempDir;
+    use clap::Parser;
+
+    // Un cuadrado guardado como OBJ en `tmp`
+    fn square(tmp: &TempDir) -> String {
+        let p = |x, y| Point3D { x, y, z: 0.0 };
+        let mesh = Mesh::new(
+            vec![p(0.0, 0.0), p(1.0, 0.0), p(1.0, 1.0), p(0.0, 1.0)],
+            vec![vec![0, 1, 2, 3]],
+        );
+        let src = tmp.file("square.obj");
+        files::save_mesh(&src, &mesh, &SaveOptions::default()).unwrap();
+        src
+    }
+
+    fn command(args: &[&str]) -> Command {
+        let cli = Cli::try_parse_from(std::iter::once("formulars").chain(args.iter().copied()));
+        cli.unwrap().command.unwrap()
+    }
+
+    #[test]
+    fn stats_and_convert() {
+        let tmp = TempDir::new();
+        let src = square(&tmp);
+        assert_eq!(run(command(&["stats", &src])), Ok(()));
+        assert!(run(command(&["stats", &tmp.file("missing.obj")])).is_err());
+
+        for (name, ascii) in [("square.stl", false), ("square.ply", true)] {
+            let dst = tmp.file(name);
+            let mut args = vec!["convert", &src, &dst];
+            if ascii {
+                args.push("--ascii");
+            }
+            assert_eq!(run(command(&args)), Ok(()), "{name}");
+            let mesh = files::load_mesh(&dst).unwrap();
+            assert_eq!(mesh.vs.len(), 4, "{name}");
+        }
+        // STL triangula el cuadrado; PLY lo conserva
+        assert_eq!(
+            files::load_mesh(&tmp.file("square.stl")).unwrap().fs.len(),
+            2
+        );
+        assert_eq!(
+            files::load_mesh(&tmp.file("square.ply")).unwrap().fs.len(),
+            1
+        );
+        assert!(run(command(&["convert", &src, &tmp.file("square.xyz")])).is_err());
+    }
+
+    #[test]
+    fn image_size_is_capped() {
+        let tmp = TempDir::new();
+        let src = square(&tmp);
+        let dst = tmp.file("square.png");
+        assert_eq!(
+            run(command(&["render", &src, "-o", &dst, "--size", "32x24"])),
+            Ok(())
+        );
+        assert!(std::fs::metadata(&dst).is_ok());
+
+        let err = run(command(&[
+            "render",
+            &src,
+            "-o",
+            &dst,
+            "--size",
+            "100000x100000",
+        ]));
+        assert!(err.unwrap_err().contains("too large"));
+        let gif = tmp.file("square.gif");
+        let err = run(command(&[
+            "animate", &src, "-o", &gif, "--size", "64x20000",
+        ]));
+        assert!(err.unwrap_err().contains("too large"));
+    }
+}
@@ -1,6 +1,16 @@
+mod anim;
 mod app;
+mod camera;
+mod cli;
 mod files;
-mod penger;
+mod headless;
+mod math;
+mod models;
+mod raster;
+mod render;
+mod svg;
+#[cfg(test)]
+mod test_utils;
 mod types;
 
 // fn main() {
@@ -8,8 +18,8 @@ mod types;
 //     let ll: types::Lines;
 //     let p: types::Point3D;
 //
-//     println!("point3d[300]  = {:?}", crate::penger::VS[300]);
-//     println!("line[300]  = {:?}", crate::penger::FS[300]);
+//     println!("point3d[300]  = {:?}", crate::models::penger::VS[300]);
+//     println!("line[300]  = {:?}", crate::models::penger::FS[300]);
 // }
 
 // -- Native App: ---------------------------------------------------------
@@ -17,11 +27,29 @@ mod types;
 #[cfg(not(target_arch = "wasm32"))]
 fn main() -> eframe::Result {
     //env_logger::init(); // Log to stderr (if you run with `RUST_LOG=debug`).
+    use clap::Parser;
+    let mut cli = cli::Cli::parse();
+    if let Some(command) = cli.command.take() {
+        if let Err(e) = headless::run(command) {
+            eprintln!("error: {e}");
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
+    // rfd habla con el portal de escritorio por D-Bus sobre tokio: sus
+    // diálogos bloqueantes necesitan un runtime activo en el hilo de la UI
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(1)
+        .enable_all()
+        .build()
+        .expect("Failed to start the tokio runtime");
+    let _runtime = runtime.enter();
 
     let native_options = eframe::NativeOptions {
         viewport: egui::ViewportBuilder::default()
             .with_app_id("Floater") // niri uses this app_id to make the window floating
-            .with_inner_size([1024.0, 768.0])
+            .with_inner_size(cli.window_size.unwrap_or([1024.0, 768.0]))
             .with_min_inner_size([500.0, 300.0])
             .with_icon(
                 // NOTE: Adding an icon is optional
@@ -34,9 +62,9 @@ fn main() -> eframe::Result {
     eframe::run_native(
         "Penger3D GUI",
         native_options,
-        Box::new(|cc| {
-            let cc = cc;
-            Ok(Box::new(app::App3D::new()))
+        Box::new(move |cc| {
+            let app = app::App3D::new().with_storage(cc.storage).with_cli(&cli);
+            Ok(Box::new(app))
         }),
     )
 }
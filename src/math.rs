@@ -0,0 +1,716 @@
+// Copyright (C) 2026  Antonio-Miguel Corbi Bellot
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+// Álgebra lineal: vectores, matrices 3x3 y 4x4 y cuaterniones.
+//
+// Las matrices se guardan por filas y multiplican a vectores columna:
+// `m * v`, de modo que `a * b` aplica primero `b`. Los ángulos van en
+// grados, como en el resto del programa. El espacio de la cámara es el de
+// la pantalla: X a la derecha, Y hacia abajo y Z hacia delante (sistema
+// dextrógiro)
+
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::types::Axe;
+
+// -- Vec3: ---------------------------------------------------------------
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Vec3 {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+impl Vec3 {
+    pub const ZERO: Vec3 = Vec3::new(0.0, 0.0, 0.0);
+    pub const X: Vec3 = Vec3::new(1.0, 0.0, 0.0);
+    pub const Y: Vec3 = Vec3::new(0.0, 1.0, 0.0);
+    pub const Z: Vec3 = Vec3::new(0.0, 0.0, 1.0);
+
+    pub const fn new(x: f32, y: f32, z: f32) -> Self {
+        Self { x, y, z }
+    }
+
+    pub fn dot(self, o: Vec3) -> f32 {
+        self.x * o.x + self.y * o.y + self.z * o.z
+    }
+
+    pub fn cross(self, o: Vec3) -> Vec3 {
+        Vec3::new(
+            self.y * o.z - self.z * o.y,
+            self.z * o.x - self.x * o.z,
+            self.x * o.y - self.y * o.x,
+        )
+    }
+
+    pub fn length_squared(self) -> f32 {
+        self.dot(self)
+    }
+
+    pub fn length(self) -> f32 {
+        self.length_squared().sqrt()
+    }
+
+    // Vector unitario en la misma dirección; el nulo se queda como está
+    pub fn normalize(self) -> Vec3 {
+        let len = self.length();
+        if len > 0.0 { self / len } else { self }
+    }
+
+    pub fn lerp(self, o: Vec3, t: f32) -> Vec3 {
+        self + (o - self) * t
+    }
+
+    pub fn min(self, o: Vec3) -> Vec3 {
+        Vec3::new(self.x.min(o.x), self.y.min(o.y), self.z.min(o.z))
+    }
+
+    pub fn max(self, o: Vec3) -> Vec3 {
+        Vec3::new(self.x.max(o.x), self.y.max(o.y), self.z.max(o.z))
+    }
+
+    // Punto (w = 1) en coordenadas homogéneas
+    pub fn extend(self, w: f32) -> Vec4 {
+        Vec4::new(self.x, self.y, self.z, w)
+    }
+
+    // Giro de `angle` grados alrededor de uno de los ejes
+    pub fn rotate(self, angle: f32, axe: Axe) -> Vec3 {
+        self.rotate_axis(angle, axe.unit())
+    }
+
+    // Giro alrededor del eje unitario `axis` que pasa por el origen
+    pub fn rotate_axis(self, angle: f32, axis: Vec3) -> Vec3 {
+        Quat::from_axis_angle(axis, angle).rotate(self)
+    }
+
+    // Giro alrededor del eje unitario `axis` que pasa por `pivot`
+    pub fn rotate_around(self, angle: f32, axis: Vec3, pivot: Vec3) -> Vec3 {
+        (self - pivot).rotate_axis(angle, axis) + pivot
+    }
+}
+
+impl Add for Vec3 {
+    type Output = Vec3;
+    fn add(self, o: Vec3) -> Vec3 {
+        Vec3::new(self.x + o.x, self.y + o.y, self.z + o.z)
+    }
+}
+
+impl Sub for Vec3 {
+    type Output = Vec3;
+    fn sub(self, o: Vec3) -> Vec3 {
+        Vec3::new(self.x - o.x, self.y - o.y, self.z - o.z)
+    }
+}
+
+impl Neg for Vec3 {
+    type Output = Vec3;
+    fn neg(self) -> Vec3 {
+        Vec3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl Mul<f32> for Vec3 {
+    type Output = Vec3;
+    fn mul(self, k: f32) -> Vec3 {
+        Vec3::new(self.x * k, self.y * k, self.z * k)
+    }
+}
+
+impl Mul<Vec3> for f32 {
+    type Output = Vec3;
+    fn mul(self, v: Vec3) -> Vec3 {
+        v * self
+    }
+}
+
+impl Div<f32> for Vec3 {
+    type Output = Vec3;
+    fn div(self, k: f32) -> Vec3 {
+        Vec3::new(self.x / k, self.y / k, self.z / k)
+    }
+}
+
+impl AddAssign for Vec3 {
+    fn add_assign(&mut self, o: Vec3) {
+        *self = *self + o;
+    }
+}
+
+impl SubAssign for Vec3 {
+    fn sub_assign(&mut self, o: Vec3) {
+        *self = *self - o;
+    }
+}
+
+// -- Vec4: ---------------------------------------------------------------
+// Coordenadas homogéneas
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Vec4 {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub w: f32,
+}
+
+impl Vec4 {
+    pub const fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
+        Self { x, y, z, w }
+    }
+
+    pub fn xyz(self) -> Vec3 {
+        Vec3::new(self.x, self.y, self.z)
+    }
+
+    pub fn dot(self, o: Vec4) -> f32 {
+        self.x * o.x + self.y * o.y + self.z * o.z + self.w * o.w
+    }
+
+    pub fn lerp(self, o: Vec4, t: f32) -> Vec4 {
+        self + (o - self) * t
+    }
+
+    // División de perspectiva
+    pub fn project(self) -> Vec3 {
+        self.xyz() / self.w
+    }
+}
+
+impl Add for Vec4 {
+    type Output = Vec4;
+    fn add(self, o: Vec4) -> Vec4 {
+        Vec4::new(self.x + o.x, self.y + o.y, self.z + o.z, self.w + o.w)
+    }
+}
+
+impl Sub for Vec4 {
+    type Output = Vec4;
+    fn sub(self, o: Vec4) -> Vec4 {
+        Vec4::new(self.x - o.x, self.y - o.y, self.z - o.z, self.w - o.w)
+    }
+}
+
+impl Neg for Vec4 {
+    type Output = Vec4;
+    fn neg(self) -> Vec4 {
+        Vec4::new(-self.x, -self.y, -self.z, -self.w)
+    }
+}
+
+impl Mul<f32> for Vec4 {
+    type Output = Vec4;
+    fn mul(self, k: f32) -> Vec4 {
+        Vec4::new(self.x * k, self.y * k, self.z * k, self.w * k)
+    }
+}
+
+// -- Mat3: ---------------------------------------------------------------
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Mat3 {
+    // Por filas: m[fila][columna]
+    pub m: [[f32; 3]; 3],
+}
+
+impl Mat3 {
+    pub fn from_rows(r0: Vec3, r1: Vec3, r2: Vec3) -> Self {
+        Self {
+            m: [[r0.x, r0.y, r0.z], [r1.x, r1.y, r1.z], [r2.x, r2.y, r2.z]],
+        }
+    }
+
+    pub fn scale(s: Vec3) -> Self {
+        Self {
+            m: [[s.x, 0.0, 0.0], [0.0, s.y, 0.0], [0.0, 0.0, s.z]],
+        }
+    }
+
+    pub fn row(&self, i: usize) -> Vec3 {
+        Vec3::new(self.m[i][0], self.m[i][1], self.m[i][2])
+    }
+
+    pub fn col(&self, j: usize) -> Vec3 {
+        Vec3::new(self.m[0][j], self.m[1][j], self.m[2][j])
+    }
+}
+
+// Operaciones que sólo usan las pruebas, para comprobar que las matrices
+// de giro son ortogonales
+#[cfg(test)]
+impl Mat3 {
+    pub fn rotation(axis: Vec3, angle: f32) -> Self {
+        Quat::from_axis_angle(axis, angle).to_mat3()
+    }
+
+    pub fn transpose(&self) -> Mat3 {
+        Mat3::from_rows(self.col(0), self.col(1), self.col(2))
+    }
+
+    pub fn determinant(&self) -> f32 {
+        self.row(0).dot(self.row(1).cross(self.row(2)))
+    }
+
+    // Inversa por la adjunta; None si la matriz es singular
+    pub fn inverse(&self) -> Option<Mat3> {
+        let (r0, r1, r2) = (self.row(0), self.row(1), self.row(2));
+        let det = r0.dot(r1.cross(r2));
+        if det.abs() <= f32::EPSILON {
+            return None;
+        }
+        // Las columnas de la inversa son los productos vectoriales de las
+        // filas, divididos por el determinante
+        let cols = [r1.cross(r2), r2.cross(r0), r0.cross(r1)].map(|c| c / det);
+        Some(Mat3::from_rows(cols[0], cols[1], cols[2]).transpose())
+    }
+}
+
+impl Mul for Mat3 {
+    type Output = Mat3;
+    fn mul(self, o: Mat3) -> Mat3 {
+        let mut m = [[0.0; 3]; 3];
+        for (i, row) in m.iter_mut().enumerate() {
+            for (j, cell) in row.iter_mut().enumerate() {
+                *cell = self.row(i).dot(o.col(j));
+            }
+        }
+        Mat3 { m }
+    }
+}
+
+impl Mul<Vec3> for Mat3 {
+    type Output = Vec3;
+    fn mul(self, v: Vec3) -> Vec3 {
+        Vec3::new(self.row(0).dot(v), self.row(1).dot(v), self.row(2).dot(v))
+    }
+}
+
+// -- Mat4: ---------------------------------------------------------------
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Mat4 {
+    // Por filas: m[fila][columna]
+    pub m: [[f32; 4]; 4],
+}
+
+impl Mat4 {
+    pub const IDENTITY: Mat4 = Mat4 {
+        m: [
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ],
+    };
+
+    pub fn translation(t: Vec3) -> Self {
+        let mut r = Self::IDENTITY;
+        r.m[0][3] = t.x;
+        r.m[1][3] = t.y;
+        r.m[2][3] = t.z;
+        r
+    }
+
+    pub fn scale(s: Vec3) -> Self {
+        Mat4::from(Mat3::scale(s))
+    }
+
+    pub fn rotation(axis: Vec3, angle: f32) -> Self {
+        Mat4::from(Quat::from_axis_angle(axis, angle))
+    }
+
+    // Cámara en `eye` mirando a `target`; `up` indica qué queda arriba en
+    // la pantalla
+    pub fn look_at(eye: Vec3, target: Vec3, up: Vec3) -> Self {
+        let forward = (target - eye).normalize();
+        let right = forward.cross(up).normalize();
+        // Y de la cámara hacia abajo, como en la pantalla
+        let down = forward.cross(right);
+        let rot = Mat3::from_rows(right, down, forward);
+        let mut r = Mat4::from(rot);
+        let t = -(rot * eye);
+        r.m[0][3] = t.x;
+        r.m[1][3] = t.y;
+        r.m[2][3] = t.z;
+        r
+    }
+
+    // Perspectiva con campo de visión vertical `fov_y` (grados). Deja x e
+    // y en [-1, 1] y la profundidad entre `near` y `far` en [0, 1]; w es
+    // la distancia a la cámara
+    pub fn perspective(fov_y: f32, aspect: f32, near: f32, far: f32) -> Self {
+        let f = 1.0 / (fov_y.to_radians() / 2.0).tan();
+        let depth = far / (far - near);
+        Self {
+            m: [
+                [f / aspect, 0.0, 0.0, 0.0],
+                [0.0, f, 0.0, 0.0],
+                [0.0, 0.0, depth, -near * depth],
+                [0.0, 0.0, 1.0, 0.0],
+            ],
+        }
+    }
+
+    // Proyección paralela de un volumen de `width` x `height` centrado en
+    // el eje de la cámara; la profundidad entre `near` y `far` va a [0, 1]
+    pub fn orthographic(width: f32, height: f32, near: f32, far: f32) -> Self {
+        let depth = 1.0 / (far - near);
+        Self {
+            m: [
+                [2.0 / width, 0.0, 0.0, 0.0],
+                [0.0, 2.0 / height, 0.0, 0.0],
+                [0.0, 0.0, depth, -near * depth],
+                [0.0, 0.0, 0.0, 1.0],
+            ],
+        }
+    }
+
+    pub fn row(&self, i: usize) -> Vec4 {
+        let r = self.m[i];
+        Vec4::new(r[0], r[1], r[2], r[3])
+    }
+
+    pub fn col(&self, j: usize) -> Vec4 {
+        Vec4::new(self.m[0][j], self.m[1][j], self.m[2][j], self.m[3][j])
+    }
+
+    // Punto transformado, con la división de perspectiva
+    pub fn transform_point(&self, p: Vec3) -> Vec3 {
+        (*self * p.extend(1.0)).project()
+    }
+
+    // Dirección transformada: no le afecta la traslación
+    pub fn transform_vector(&self, v: Vec3) -> Vec3 {
+        (*self * v.extend(0.0)).xyz()
+    }
+
+    // Inversa por eliminación de Gauss-Jordan con pivote parcial; None si
+    // la matriz es singular
+    pub fn inverse(&self) -> Option<Mat4> {
+        let mut a = self.m;
+        let mut inv = Mat4::IDENTITY.m;
+        for c in 0..4 {
+            let p = (c..4).max_by(|&i, &j| a[i][c].abs().total_cmp(&a[j][c].abs()))?;
+            if a[p][c].abs() <= f32::EPSILON {
+                return None;
+            }
+            a.swap(c, p);
+            inv.swap(c, p);
+            let k = 1.0 / a[c][c];
+            for j in 0..4 {
+                a[c][j] *= k;
+                inv[c][j] *= k;
+            }
+            for r in (0..4).filter(|&r| r != c) {
+                let f = a[r][c];
+                for j in 0..4 {
+                    a[r][j] -= f * a[c][j];
+                    inv[r][j] -= f * inv[c][j];
+                }
+            }
+        }
+        Some(Mat4 { m: inv })
+    }
+}
+
+impl From<Mat3> for Mat4 {
+    fn from(r: Mat3) -> Self {
+        let mut m = Mat4::IDENTITY.m;
+        for (i, row) in r.m.iter().enumerate() {
+            m[i][..3].copy_from_slice(row);
+        }
+        Mat4 { m }
+    }
+}
+
+impl From<Quat> for Mat4 {
+    fn from(q: Quat) -> Self {
+        Mat4::from(q.to_mat3())
+    }
+}
+
+impl Mul for Mat4 {
+    type Output = Mat4;
+    fn mul(self, o: Mat4) -> Mat4 {
+        let mut m = [[0.0; 4]; 4];
+        for (i, row) in m.iter_mut().enumerate() {
+            for (j, cell) in row.iter_mut().enumerate() {
+                *cell = self.row(i).dot(o.col(j));
+            }
+        }
+        Mat4 { m }
+    }
+}
+
+impl MulAssign for Mat4 {
+    fn mul_assign(&mut self, o: Mat4) {
+        *self = *self * o;
+    }
+}
+
+impl Mul<Vec4> for Mat4 {
+    type Output = Vec4;
+    fn mul(self, v: Vec4) -> Vec4 {
+        Vec4::new(
+            self.row(0).dot(v),
+            self.row(1).dot(v),
+            self.row(2).dot(v),
+            self.row(3).dot(v),
+        )
+    }
+}
+
+// -- Quat: ---------------------------------------------------------------
+// Cuaternión w + xi + yj + zk; los de norma 1 representan giros
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quat {
+    pub w: f32,
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+impl Quat {
+    // Giro de `angle` grados alrededor del eje `axis` (se normaliza)
+    pub fn from_axis_angle(axis: Vec3, angle: f32) -> Self {
+        let (s, c) = (angle.to_radians() / 2.0).sin_cos();
+        let a = axis.normalize() * s;
+        Self {
+            w: c,
+            x: a.x,
+            y: a.y,
+            z: a.z,
+        }
+    }
+
+    fn vector(self) -> Vec3 {
+        Vec3::new(self.x, self.y, self.z)
+    }
+
+    // Aplica el giro (el cuaternión debe ser unitario): v' = q v q*
+    pub fn rotate(self, v: Vec3) -> Vec3 {
+        let u = self.vector();
+        let t = 2.0 * u.cross(v);
+        v + self.w * t + u.cross(t)
+    }
+
+    pub fn to_mat3(self) -> Mat3 {
+        let Quat { w, x, y, z } = self;
+        Mat3 {
+            m: [
+                [
+                    1.0 - 2.0 * (y * y + z * z),
+                    2.0 * (x * y - w * z),
+                    2.0 * (x * z + w * y),
+                ],
+                [
+                    2.0 * (x * y + w * z),
+                    1.0 - 2.0 * (x * x + z * z),
+                    2.0 * (y * z - w * x),
+                ],
+                [
+                    2.0 * (x * z - w * y),
+                    2.0 * (y * z + w * x),
+                    1.0 - 2.0 * (x * x + y * y),
+                ],
+            ],
+        }
+    }
+}
+
+// Conjugado e inverso: sólo los usan las pruebas, porque el visor sólo
+// gira con cuaterniones unitarios
+#[cfg(test)]
+impl Quat {
+    pub fn conjugate(self) -> Quat {
+        Quat {
+            w: self.w,
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+        }
+    }
+
+    pub fn inverse(self) -> Quat {
+        let n = self.w * self.w + self.vector().length_squared();
+        let c = self.conjugate();
+        Quat {
+            w: c.w / n,
+            x: c.x / n,
+            y: c.y / n,
+            z: c.z / n,
+        }
+    }
+}
+
+// Producto de Hamilton: `a * b` gira primero con `b` y luego con `a`
+impl Mul for Quat {
+    type Output = Quat;
+    fn mul(self, o: Quat) -> Quat {
+        let (a, b) = (self.vector(), o.vector());
+        let v = self.w * b + o.w * a + a.cross(b);
+        Quat {
+            w: self.w * o.w - a.dot(b),
+            x: v.x,
+            y: v.y,
+            z: v.z,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPS: f32 = 1e-5;
+
+    fn v(x: f32, y: f32, z: f32) -> Vec3 {
+        Vec3::new(x, y, z)
+    }
+
+    fn assert_near(a: Vec3, b: Vec3) {
+        assert!((a - b).length() < EPS, "{a:?} != {b:?}");
+    }
+
+    fn assert_mat_near(a: Mat4, b: Mat4) {
+        for i in 0..4 {
+            for j in 0..4 {
+                assert!((a.m[i][j] - b.m[i][j]).abs() < 1e-4, "{a:?} != {b:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn vector_operators() {
+        let (a, b) = (v(1.0, 2.0, 3.0), v(-2.0, 0.5, 4.0));
+        assert_eq!(a + b, v(-1.0, 2.5, 7.0));
+        assert_eq!(a - b, v(3.0, 1.5, -1.0));
+        assert_eq!(-a, v(-1.0, -2.0, -3.0));
+        assert_eq!(2.0 * a, a * 2.0);
+        assert_eq!(a.dot(b), 11.0);
+        assert_eq!(Vec3::X.cross(Vec3::Y), Vec3::Z);
+        assert!(a.cross(b).dot(a).abs() < EPS);
+        assert!((a.normalize().length() - 1.0).abs() < EPS);
+        assert_eq!(Vec3::ZERO.normalize(), Vec3::ZERO);
+    }
+
+    #[test]
+    fn axis_rotations_do_not_shear() {
+        // Un cuarto de vuelta en cada eje según la regla de la mano derecha
+        assert_near(v(0.0, 1.0, 0.0).rotate(90.0, Axe::X), v(0.0, 0.0, 1.0));
+        assert_near(v(0.0, 0.0, 1.0).rotate(90.0, Axe::Y), v(1.0, 0.0, 0.0));
+        assert_near(v(1.0, 0.0, 0.0).rotate(90.0, Axe::Z), v(0.0, 1.0, 0.0));
+
+        // La distancia al origen no cambia
+        let p = v(0.3, -1.2, 2.5);
+        for axe in [Axe::X, Axe::Y, Axe::Z] {
+            assert!((p.rotate(33.0, axe).length() - p.length()).abs() < EPS);
+        }
+    }
+
+    #[test]
+    fn rotation_keeps_an_orthonormal_basis() {
+        let axis = v(1.0, 2.0, 3.0).normalize();
+        let [e1, e2, e3] = [Vec3::X, Vec3::Y, Vec3::Z].map(|e| e.rotate_axis(37.0, axis));
+        for e in [e1, e2, e3] {
+            assert!((e.length() - 1.0).abs() < EPS);
+        }
+        for (a, b) in [(e1, e2), (e2, e3), (e3, e1)] {
+            assert!(a.dot(b).abs() < EPS);
+        }
+        // Sigue siendo una base directa: e1 × e2 = e3
+        assert_near(e1.cross(e2), e3);
+        // El propio eje no se mueve
+        assert_near(axis.rotate_axis(37.0, axis), axis);
+    }
+
+    #[test]
+    fn full_turn_returns_to_the_start() {
+        let p = v(0.3, -1.2, 2.5);
+        let axis = v(-2.0, 0.5, 1.0).normalize();
+        let pivot = v(1.0, 1.0, -1.0);
+        for axe in [Axe::X, Axe::Y, Axe::Z] {
+            assert_near(p.rotate(360.0, axe), p);
+        }
+        assert_near(p.rotate_axis(360.0, axis), p);
+        assert_near(p.rotate_around(360.0, axis, pivot), p);
+        // Diez pasos de 36° también dan la vuelta completa
+        let steps = (0..10).fold(p, |r, _| r.rotate_around(36.0, axis, pivot));
+        assert_near(steps, p);
+    }
+
+    #[test]
+    fn pivot_stays_in_place() {
+        let axis = v(0.0, 1.0, 1.0).normalize();
+        let pivot = v(2.0, -3.0, 0.5);
+        assert_near(pivot.rotate_around(123.0, axis, pivot), pivot);
+        // Media vuelta en Z alrededor de (1, 0, 0) lleva el origen a (2, 0, 0)
+        assert_near(
+            Vec3::ZERO.rotate_around(180.0, Vec3::Z, v(1.0, 0.0, 0.0)),
+            v(2.0, 0.0, 0.0),
+        );
+    }
+
+    #[test]
+    fn quaternions_matrices_and_rotations_agree() {
+        let (qa, qb) = (
+            Quat::from_axis_angle(v(1.0, 1.0, 0.0), 40.0),
+            Quat::from_axis_angle(Vec3::Z, -75.0),
+        );
+        let p = v(0.7, -0.2, 1.3);
+        // Componer cuaterniones es componer giros
+        assert_near((qa * qb).rotate(p), qa.rotate(qb.rotate(p)));
+        assert_near((qa * qb).to_mat3() * p, qa.to_mat3() * (qb.to_mat3() * p));
+        assert_near(qa.inverse().rotate(qa.rotate(p)), p);
+        // La matriz de un giro es ortogonal: su inversa es la traspuesta
+        let r = qa.to_mat3();
+        assert!((r.determinant() - 1.0).abs() < EPS);
+        assert_mat_near(Mat4::from(r.inverse().unwrap()), Mat4::from(r.transpose()));
+    }
+
+    #[test]
+    fn inverse_undoes_the_matrix() {
+        let m = Mat4::translation(v(1.0, -2.0, 3.0))
+            * Mat4::rotation(v(0.2, 1.0, -0.4), 63.0)
+            * Mat4::scale(v(2.0, 0.5, 3.0));
+        assert_mat_near(m * m.inverse().unwrap(), Mat4::IDENTITY);
+        assert_mat_near(m.inverse().unwrap() * m, Mat4::IDENTITY);
+        assert!(Mat4::scale(v(1.0, 0.0, 1.0)).inverse().is_none());
+
+        let m3 = Mat3::rotation(Vec3::Y, 30.0) * Mat3::scale(v(2.0, 3.0, 4.0));
+        let p = v(1.0, 2.0, 3.0);
+        assert_near(m3.inverse().unwrap() * (m3 * p), p);
+    }
+
+    #[test]
+    fn camera_and_perspective() {
+        // Cámara en -Z mirando al origen con la pantalla "hacia arriba" en -Y:
+        // el espacio de la cámara sólo se desplaza
+        let view = Mat4::look_at(v(0.0, 0.0, -5.0), Vec3::ZERO, -Vec3::Y);
+        assert_mat_near(view, Mat4::translation(v(0.0, 0.0, 5.0)));
+        // Desde +Z con Y hacia arriba, X sigue a la derecha y Y se invierte
+        let view = Mat4::look_at(v(0.0, 0.0, 5.0), Vec3::ZERO, Vec3::Y);
+        assert_near(view.transform_point(v(1.0, 1.0, 0.0)), v(1.0, -1.0, 5.0));
+
+        let proj = Mat4::perspective(90.0, 2.0, 1.0, 10.0);
+        let near = proj * v(1.0, 1.0, 1.0).extend(1.0);
+        assert!((near.w - 1.0).abs() < EPS);
+        assert_near(near.project(), v(0.5, 1.0, 0.0));
+        assert_near(proj.transform_point(v(0.0, 0.0, 10.0)), v(0.0, 0.0, 1.0));
+
+        // En paralela el tamaño no depende de la distancia
+        let ortho = Mat4::orthographic(4.0, 2.0, 1.0, 11.0);
+        assert_near(ortho.transform_point(v(2.0, 1.0, 1.0)), v(1.0, 1.0, 0.0));
+        assert_near(ortho.transform_point(v(2.0, 1.0, 11.0)), v(1.0, 1.0, 1.0));
+    }
+}
@@ -0,0 +1,19 @@
+// Copyright (C) 2026  Antonio-M. Corbi Bellot
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+// Modelos embebidos: build.rs genera un módulo por cada OBJ listado en
+// assets/models/models.txt (p.e. `penger::VS`, `penger::FS`) y la tabla
+// `MODELS` con todos ellos
+include!(concat!(env!("OUT_DIR"), "/models.rs"));
@@ -0,0 +1,391 @@
+// Copyright (C) 2026  Antonio-Miguel Corbi Bellot
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+// Rasterizador en memoria, sin ventana ni GPU: pinta una `RenderList` con
+// líneas y puntos suavizados, triángulos rellenos y búfer de profundidad,
+// y guarda el resultado como PNG
+
+use std::io::{self, Write};
+
+use egui::Color32;
+
+use crate::camera::Projection;
+use crate::files::create;
+use crate::render::{Primitive, RenderList, ScreenPoint};
+use crate::types::Point2D;
+
+// Margen relativo de profundidad: las aristas de una cara deben verse
+// aunque estén a la misma distancia que ella
+const DEPTH_BIAS: f32 = 1e-3;
+
+// Imagen RGBA de 8 bits por canal con su búfer de profundidad
+pub struct Canvas {
+    width: usize,
+    height: usize,
+    pixels: Vec<Color32>,
+    depth: Vec<f32>,
+}
+
+impl Canvas {
+    pub fn new(width: usize, height: usize, background: Color32) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![background; width * height],
+            depth: vec![f32::INFINITY; width * height],
+        }
+    }
+
+    #[cfg(test)]
+    pub fn pixel(&self, x: usize, y: usize) -> Color32 {
+        self.pixels[y * self.width + x]
+    }
+
+    pub fn draw(&mut self, list: &RenderList) {
+        for item in &list.items {
+            match *item {
+                Primitive::Triangle { vs, color } => self.triangle(list.projection, vs, color),
+                Primitive::Line { a, b, width, color } => {
+                    self.line(list.projection, a, b, width, color)
+                }
+                Primitive::Point { p, radius, color } => self.point(p, radius, color),
+            }
+        }
+    }
+
+    // Índice del píxel si cae dentro de la imagen
+    fn index(&self, x: i64, y: i64) -> Option<usize> {
+        (x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height)
+            .then(|| y as usize * self.width + x as usize)
+    }
+
+    // ¿Se ve un punto a esa profundidad? Fuera de la imagen, siempre
+    pub fn is_visible(&self, p: Point2D, depth: f32) -> bool {
+        if !p.x.is_finite() || !p.y.is_finite() {
+            return false;
+        }
+        self.index(p.x.floor() as i64, p.y.floor() as i64)
+            .is_none_or(|i| depth <= self.depth[i] * (1.0 + DEPTH_BIAS))
+    }
+
+    // Mezcla `color` con la cobertura dada si supera la prueba de
+    // profundidad; no escribe en el búfer de profundidad
+    fn blend(&mut self, x: i64, y: i64, depth: f32, color: Color32, coverage: f32) {
+        let Some(i) = self.index(x, y) else {
+            return;
+        };
+        if coverage <= 0.0 || depth > self.depth[i] * (1.0 + DEPTH_BIAS) {
+            return;
+        }
+        let [r, g, b, a] = color.to_srgba_unmultiplied();
+        let alpha = coverage.min(1.0) * a as f32 / 255.0;
+        let [dr, dg, db, da] = self.pixels[i].to_srgba_unmultiplied();
+        let mix = |s: u8, d: u8| (s as f32 * alpha + d as f32 * (1.0 - alpha)).round() as u8;
+        self.pixels[i] = Color32::from_rgba_unmultiplied(
+            mix(r, dr),
+            mix(g, dg),
+            mix(b, db),
+            da.max((alpha * 255.0).round() as u8),
+        );
+    }
+
+    // Triángulo relleno con prueba y escritura de profundidad. Se muestrea
+    // el centro de cada píxel
+    fn triangle(&mut self, projection: Projection, vs: [ScreenPoint; 3], color: Color32) {
+        let [a, b, c] = vs.map(|v| v.pos);
+        let area = edge(a, b, c);
+        if area == 0.0 || !area.is_finite() {
+            return;
+        }
+        let min_x = a.x.min(b.x).min(c.x).floor().max(0.0) as i64;
+        let max_x = a.x.max(b.x).max(c.x).ceil().min(self.width as f32 - 1.0) as i64;
+        let min_y = a.y.min(b.y).min(c.y).floor().max(0.0) as i64;
+        let max_y = a.y.max(b.y).max(c.y).ceil().min(self.height as f32 - 1.0) as i64;
+        let depths = vs.map(|v| v.depth);
+
+        for y in min_y..=max_y {
+            for x in min_x..=max_x {
+                let p = Point2D {
+                    x: x as f32 + 0.5,
+                    y: y as f32 + 0.5,
+                };
+                let w0 = edge(b, c, p) / area;
+                let w1 = edge(c, a, p) / area;
+                let w2 = edge(a, b, p) / area;
+                if w0 < 0.0 || w1 < 0.0 || w2 < 0.0 {
+                    continue;
+                }
+                let depth = interpolate_depth(projection, depths, [w0, w1, w2]);
+                let i = y as usize * self.width + x as usize;
+                if depth < self.depth[i] {
+                    self.depth[i] = depth;
+                    self.pixels[i] = color;
+                }
+            }
+        }
+    }
+
+    // Segmento suavizado: la cobertura de cada píxel depende de su
+    // distancia al segmento. Se recorre el eje mayor y, en cada paso, sólo
+    // la franja de píxeles que puede tocar el trazo
+    fn line(
+        &mut self,
+        projection: Projection,
+        a: ScreenPoint,
+        b: ScreenPoint,
+        width: f32,
+        color: Color32,
+    ) {
+        let (p, q) = (a.pos, b.pos);
+        if ![p.x, p.y, q.x, q.y].iter().all(|c| c.is_finite()) {
+            return;
+        }
+        let Some((t0, t1)) = self.clip(p, q) else {
+            return;
+        };
+        let (dx, dy) = (q.x - p.x, q.y - p.y);
+        let len2 = dx * dx + dy * dy;
+        let half = width.max(1.0) / 2.0;
+        // Trazos más finos que un píxel se pintan más tenues
+        let intensity = width.min(1.0);
+        let reach = (half + 1.0).ceil() as i64;
+        let steep = dy.abs() > dx.abs();
+        let major = |t: f32| if steep { p.y + t * dy } else { p.x + t * dx };
+        let (m0, m1) = (major(t0), major(t1));
+        let (from, to) = (m0.min(m1).floor() as i64, m0.max(m1).ceil() as i64);
+
+        for m in from - reach..=to + reach {
+            // Punto del segmento en este paso del eje mayor
+            let t = if len2 == 0.0 {
+                0.0
+            } else if steep {
+                (m as f32 + 0.5 - p.y) / dy
+            } else {
+                (m as f32 + 0.5 - p.x) / dx
+            };
+            let t = t.clamp(t0, t1);
+            let minor = if steep { p.x + t * dx } else { p.y + t * dy };
+            for n in minor.floor() as i64 - reach..=minor.floor() as i64 + reach {
+                let (x, y) = if steep { (n, m) } else { (m, n) };
+                let c = Point2D {
+                    x: x as f32 + 0.5,
+                    y: y as f32 + 0.5,
+                };
+                // Punto más cercano del segmento al centro del píxel
+                let s = if len2 == 0.0 {
+                    0.0
+                } else {
+                    (((c.x - p.x) * dx + (c.y - p.y) * dy) / len2).clamp(0.0, 1.0)
+                };
+                let dist = ((c.x - p.x - s * dx).powi(2) + (c.y - p.y - s * dy).powi(2)).sqrt();
+                let coverage = (half + 0.5 - dist).clamp(0.0, 1.0) * intensity;
+                let depth = interpolate_depth(projection, [a.depth, b.depth], [1.0 - s, s]);
+                self.blend(x, y, depth, color, coverage);
+            }
+        }
+    }
+
+    // Parte del segmento p→q (parámetros t0..t1) que cae dentro de la
+    // imagen ampliada un píxel por cada lado (Liang-Barsky)
+    fn clip(&self, p: Point2D, q: Point2D) -> Option<(f32, f32)> {
+        let (dx, dy) = (q.x - p.x, q.y - p.y);
+        let (max_x, max_y) = (self.width as f32 + 1.0, self.height as f32 + 1.0);
+        let (mut t0, mut t1) = (0.0f32, 1.0f32);
+        for (d, q) in [
+            (-dx, p.x + 1.0),
+            (dx, max_x - p.x),
+            (-dy, p.y + 1.0),
+            (dy, max_y - p.y),
+        ] {
+            if d == 0.0 {
+                if q < 0.0 {
+                    return None;
+                }
+            } else {
+                let t = q / d;
+                if d < 0.0 {
+                    t0 = t0.max(t);
+                } else {
+                    t1 = t1.min(t);
+                }
+            }
+        }
+        (t0 <= t1).then_some((t0, t1))
+    }
+
+    // Disco suavizado
+    fn point(&mut self, p: ScreenPoint, radius: f32, color: Color32) {
+        let c = p.pos;
+        if !c.x.is_finite() || !c.y.is_finite() {
+            return;
+        }
+        let r = radius.max(0.5);
+        for y in (c.y - r - 1.0).floor() as i64..=(c.y + r + 1.0).ceil() as i64 {
+            for x in (c.x - r - 1.0).floor() as i64..=(c.x + r + 1.0).ceil() as i64 {
+                let dist = ((x as f32 + 0.5 - c.x).powi(2) + (y as f32 + 0.5 - c.y).powi(2)).sqrt();
+                self.blend(x, y, p.depth, color, r + 0.5 - dist);
+            }
+        }
+    }
+
+    // Píxeles RGBA por filas, sin premultiplicar
+    pub fn to_rgba(&self) -> Vec<u8> {
+        self.pixels
+            .iter()
+            .flat_map(|c| c.to_srgba_unmultiplied())
+            .collect()
+    }
+
+    pub fn write_png(&self, fname: &str) -> io::Result<()> {
+        let mut w = create(fname)?;
+        let mut encoder = png::Encoder::new(&mut w, self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder.write_header()?.write_image_data(&self.to_rgba())?;
+        w.flush()
+    }
+}
+
+// Profundidad en un punto de la primitiva a partir de los pesos de sus
+// vértices: en perspectiva lo que varía linealmente en pantalla es 1/z; en
+// paralela, la propia z
+fn interpolate_depth<const N: usize>(
+    projection: Projection,
+    depths: [f32; N],
+    weights: [f32; N],
+) -> f32 {
+    let pairs = depths.into_iter().zip(weights);
+    match projection {
+        Projection::Perspective => 1.0 / pairs.map(|(d, w)| w / d).sum::<f32>(),
+        Projection::Orthographic => pairs.map(|(d, w)| w * d).sum(),
+    }
+}
+
+// Doble del área con signo del triángulo a-b-c
+fn edge(a: Point2D, b: Point2D, c: Point2D) -> f32 {
+    (b.x - a.x) * (c.y - a.y) - (b.y - a.y) * (c.x - a.x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TempDir;
+
+    const BLACK: Color32 = Color32::BLACK;
+
+    fn sp(x: f32, y: f32, depth: f32) -> ScreenPoint {
+        ScreenPoint {
+            pos: Point2D { x, y },
+            depth,
+        }
+    }
+
+    fn canvas(items: Vec<Primitive>) -> Canvas {
+        canvas_with(Projection::Perspective, items)
+    }
+
+    fn canvas_with(projection: Projection, items: Vec<Primitive>) -> Canvas {
+        let mut canvas = Canvas::new(32, 32, BLACK);
+        canvas.draw(&RenderList { items, projection });
+        canvas
+    }
+
+    #[test]
+    fn nearer_triangle_wins_regardless_of_order() {
+        let tri = |depth, color| Primitive::Triangle {
+            vs: [
+                sp(0.0, 0.0, depth),
+                sp(32.0, 0.0, depth),
+                sp(0.0, 32.0, depth),
+            ],
+            color,
+        };
+        for items in [
+            vec![tri(1.0, Color32::RED), tri(2.0, Color32::BLUE)],
+            vec![tri(2.0, Color32::BLUE), tri(1.0, Color32::RED)],
+        ] {
+            let c = canvas(items);
+            assert_eq!(c.pixel(4, 4), Color32::RED);
+            // Fuera del triángulo queda el fondo
+            assert_eq!(c.pixel(30, 30), BLACK);
+        }
+    }
+
+    #[test]
+    fn lines_are_antialiased_and_depth_tested() {
+        let line = |y, depth| Primitive::Line {
+            a: sp(2.0, y, depth),
+            b: sp(30.0, y, depth),
+            width: 1.0,
+            color: Color32::WHITE,
+        };
+        // Centrada en la fila 10: cobertura completa; entre las filas 20 y
+        // 21: la mitad en cada una
+        let c = canvas(vec![line(10.5, 1.0), line(21.0, 1.0)]);
+        assert_eq!(c.pixel(16, 10), Color32::WHITE);
+        assert_eq!(c.pixel(16, 9), BLACK);
+        let half = c.pixel(16, 20).r();
+        assert!((100..156).contains(&half), "{half}");
+        assert_eq!(c.pixel(16, 21).r(), half);
+
+        // Una línea detrás de un triángulo no se ve; sobre su borde, sí
+        let wall = Primitive::Triangle {
+            vs: [sp(0.0, 0.0, 1.0), sp(64.0, 0.0, 1.0), sp(0.0, 64.0, 1.0)],
+            color: Color32::RED,
+        };
+        let c = canvas(vec![wall, line(10.5, 2.0), line(20.5, 1.0)]);
+        assert_eq!(c.pixel(8, 10), Color32::RED);
+        assert_eq!(c.pixel(8, 20), Color32::WHITE);
+    }
+
+    #[test]
+    fn depth_follows_the_projection() {
+        // Un plano inclinado de profundidad 1 a 3: a media pantalla está a
+        // 1.5 en perspectiva y a 2 en paralela, así que una línea a 1.8
+        // queda detrás en el primer caso y delante en el segundo
+        let slope = Primitive::Triangle {
+            vs: [sp(0.0, 0.0, 1.0), sp(32.0, 0.0, 3.0), sp(0.0, 64.0, 1.0)],
+            color: Color32::RED,
+        };
+        let line = Primitive::Line {
+            a: sp(16.5, 2.0, 1.8),
+            b: sp(16.5, 6.0, 1.8),
+            width: 1.0,
+            color: Color32::WHITE,
+        };
+        let c = canvas_with(Projection::Perspective, vec![slope, line]);
+        assert_eq!(c.pixel(16, 4), Color32::RED);
+        let c = canvas_with(Projection::Orthographic, vec![slope, line]);
+        assert_eq!(c.pixel(16, 4), Color32::WHITE);
+    }
+
+    #[test]
+    fn png_has_the_canvas_size() {
+        let c = canvas(vec![Primitive::Point {
+            p: sp(16.0, 16.0, 1.0),
+            radius: 3.0,
+            color: Color32::GREEN,
+        }]);
+        assert_eq!(c.pixel(15, 15), Color32::GREEN);
+
+        let tmp = TempDir::new();
+        let dst = tmp.file("point.png");
+        c.write_png(&dst).unwrap();
+        let decoder =
+            png::Decoder::new(std::io::BufReader::new(std::fs::File::open(&dst).unwrap()));
+        let reader = decoder.read_info().unwrap();
+        assert_eq!((reader.info().width, reader.info().height), (32, 32));
+    }
+}
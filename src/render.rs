@@ -0,0 +1,612 @@
+// Copyright (C) 2026  Antonio-Miguel Corbi Bellot
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+// Lista de primitivas 2D ya proyectadas, independiente de quién las pinte
+// (egui en el visor, el rasterizador en memoria sin ventana)
+
+use egui::{Color32, Rect};
+
+use crate::camera::{self, Camera, Projection};
+use crate::math::{Mat4, Vec3, Vec4};
+use crate::types::{Axe, Mesh, Point2D, Point3D};
+
+// Velocidad de giro del modelo, en grados por segundo
+pub const MIN_ANGLE_STEP: f32 = 0.00;
+pub const MAX_ANGLE_STEP: f32 = 360.00;
+
+// Punto en pantalla junto con su distancia a la cámara
+#[derive(Debug, Clone, Copy)]
+pub struct ScreenPoint {
+    pub pos: Point2D,
+    pub depth: f32,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Primitive {
+    Triangle {
+        vs: [ScreenPoint; 3],
+        color: Color32,
+    },
+    Line {
+        a: ScreenPoint,
+        b: ScreenPoint,
+        width: f32,
+        color: Color32,
+    },
+    Point {
+        p: ScreenPoint,
+        radius: f32,
+        color: Color32,
+    },
+}
+
+// Primitivas en el orden en que se deben pintar: caras (de la más lejana a
+// la más cercana, cada una con sus aristas), aristas y vértices
+#[derive(Debug, Default)]
+pub struct RenderList {
+    pub items: Vec<Primitive>,
+    // Proyección con la que se generaron: decide cómo varía la profundidad
+    // a lo largo de cada primitiva en pantalla
+    pub projection: Projection,
+}
+
+// Cómo se ve el modelo: encaje, giros, zoom y qué se dibuja
+#[derive(Debug, Clone)]
+pub struct View {
+    pub center: Point3D,
+    pub scale: f32,
+    // Giros sucesivos, en grados
+    pub rotation: Vec<(Axe, f32)>,
+    pub camera: Camera,
+    pub draw_vs: bool,
+    pub draw_fs: bool,
+    // Caja alineada con los ejes que contiene al modelo
+    pub draw_bbox: bool,
+    // Caras rellenas e iluminadas
+    pub solid: bool,
+    pub light: Light,
+    pub line_width: f32,
+    pub point_radius: f32,
+}
+
+// Luz direccional más un término ambiente, para el sombreado plano
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Light {
+    // De dónde llega la luz, relativa a la cámara: X a la derecha, Y hacia
+    // arriba y Z hacia quien mira. Así el modelo sigue iluminado al girar
+    // la cámara a su alrededor
+    pub direction: Vec3,
+    pub ambient: f32,
+    pub diffuse: f32,
+}
+
+impl Default for Light {
+    fn default() -> Self {
+        Self {
+            direction: Vec3::new(-0.4, 0.6, 1.0),
+            ambient: 0.25,
+            diffuse: 0.75,
+        }
+    }
+}
+
+impl Light {
+    // Brillo de una cara con esa normal; `light` es la dirección de la luz
+    // ya en el mundo. Las caras se iluminan por los dos lados: una cara
+    // vista por detrás usa la normal invertida
+    fn intensity(&self, normal: Vec3, light: Vec3, to_eye: Vec3) -> f32 {
+        let normal = if normal.dot(to_eye) < 0.0 {
+            -normal
+        } else {
+            normal
+        };
+        self.ambient + self.diffuse * normal.dot(light).max(0.0)
+    }
+}
+
+impl View {
+    // Matriz del modelo: lo encaja en el origen y lo gira
+    fn model(&self) -> Mat4 {
+        let rotation = self
+            .rotation
+            .iter()
+            .fold(Mat4::IDENTITY, |m, &(axe, angle)| {
+                Mat4::rotation(axe.unit(), angle) * m
+            });
+        rotation
+            * Mat4::scale(Vec3::new(self.scale, self.scale, self.scale))
+            * Mat4::translation(-self.center)
+    }
+}
+
+const BBOX_COLOR: Color32 = Color32::LIGHT_BLUE;
+
+// Vértice ya transformado, antes de la división de perspectiva:
+// coordenadas de recorte y distancia a la cámara. Las dos varían
+// linealmente a lo largo de una arista, así que se pueden interpolar
+#[derive(Debug, Clone, Copy)]
+struct ClipVertex {
+    clip: Vec4,
+    depth: f32,
+}
+
+impl ClipVertex {
+    fn lerp(self, o: ClipVertex, t: f32) -> ClipVertex {
+        ClipVertex {
+            clip: self.clip.lerp(o.clip, t),
+            depth: self.depth + (o.depth - self.depth) * t,
+        }
+    }
+
+    fn to_screen(self, screenr: Rect) -> ScreenPoint {
+        ScreenPoint {
+            pos: camera::to_viewport(self.clip.project(), screenr),
+            depth: self.depth,
+        }
+    }
+}
+
+// Planos del volumen visible en coordenadas de recorte: un punto está
+// dentro si p · plano >= 0. Cercano (z >= 0), lejano (z <= w) y laterales
+// (-w <= x, y <= w)
+const NEAR_FAR: [Vec4; 2] = [
+    Vec4::new(0.0, 0.0, 1.0, 0.0),
+    Vec4::new(0.0, 0.0, -1.0, 1.0),
+];
+const FRUSTUM: [Vec4; 6] = [
+    NEAR_FAR[0],
+    NEAR_FAR[1],
+    Vec4::new(1.0, 0.0, 0.0, 1.0),
+    Vec4::new(-1.0, 0.0, 0.0, 1.0),
+    Vec4::new(0.0, 1.0, 0.0, 1.0),
+    Vec4::new(0.0, -1.0, 0.0, 1.0),
+];
+
+fn inside(v: ClipVertex, planes: &[Vec4]) -> bool {
+    planes.iter().all(|pl| v.clip.dot(*pl) >= 0.0)
+}
+
+// Recorta el segmento a→b con los planos (Liang–Barsky en coordenadas
+// homogéneas); None si queda entero fuera
+fn clip_segment(a: ClipVertex, b: ClipVertex, planes: &[Vec4]) -> Option<(ClipVertex, ClipVertex)> {
+    let (mut t0, mut t1) = (0.0_f32, 1.0_f32);
+    for pl in planes {
+        let (da, db) = (a.clip.dot(*pl), b.clip.dot(*pl));
+        if da < 0.0 && db < 0.0 {
+            return None;
+        }
+        let t = da / (da - db);
+        if da < 0.0 {
+            t0 = t0.max(t);
+        } else if db < 0.0 {
+            t1 = t1.min(t);
+        }
+    }
+    (t0 <= t1).then(|| (a.lerp(b, t0), a.lerp(b, t1)))
+}
+
+// Recorta el polígono con los planos (Sutherland–Hodgman)
+fn clip_polygon(mut poly: Vec<ClipVertex>, planes: &[Vec4]) -> Vec<ClipVertex> {
+    for pl in planes {
+        if poly.is_empty() {
+            break;
+        }
+        let mut out = Vec::with_capacity(poly.len() + 2);
+        for (i, &a) in poly.iter().enumerate() {
+            let b = poly[(i + 1) % poly.len()];
+            let (da, db) = (a.clip.dot(*pl), b.clip.dot(*pl));
+            if da >= 0.0 {
+                out.push(a);
+            }
+            if (da >= 0.0) != (db >= 0.0) {
+                out.push(a.lerp(b, da / (da - db)));
+            }
+        }
+        poly = out;
+    }
+    poly
+}
+
+// Proyecta la malla sobre `screenr` y genera las primitivas a pintar. Todo
+// se recorta antes de proyectar: nada de lo que quede detrás de la cámara
+// (o más cerca que el plano cercano) llega a dividirse por w
+pub fn build(mesh: &Mesh, view: &View, screenr: Rect) -> RenderList {
+    // Una única matriz modelo-vista-proyección por fotograma
+    let model_view = view.camera.view() * view.model();
+    let mvp = view.camera.projection_matrix(camera::aspect(screenr)) * model_view;
+    // La distancia a la cámara es la Z en el espacio de la cámara (en
+    // perspectiva coincide con w, pero en paralela w siempre vale 1)
+    let depth_row = model_view.row(2);
+    let transformed: Vec<ClipVertex> = mesh
+        .vs
+        .iter()
+        .map(|&v| {
+            let p = v.extend(1.0);
+            ClipVertex {
+                clip: mvp * p,
+                depth: depth_row.dot(p),
+            }
+        })
+        .collect();
+
+    let mut list = RenderList {
+        items: vec![],
+        projection: view.camera.projection,
+    };
+    if view.solid {
+        solid_faces(&mut list, mesh, view, &transformed, screenr);
+    }
+    if view.draw_fs {
+        // Con caras rellenas, sus aristas ya van con ellas; quedan las líneas
+        let lines = mesh
+            .ls
+            .iter()
+            .flat_map(|l| l.windows(2).map(|w| (w[0], w[1], None)));
+        let segments: Box<dyn Iterator<Item = (usize, usize, Option<usize>)>> = if view.solid {
+            Box::new(lines)
+        } else {
+            Box::new(mesh.segments())
+        };
+        for (ia, ib, material) in segments {
+            if let Some((a, b)) = clip_segment(transformed[ia], transformed[ib], &FRUSTUM) {
+                list.items.push(Primitive::Line {
+                    a: a.to_screen(screenr),
+                    b: b.to_screen(screenr),
+                    width: view.line_width,
+                    color: edge_color(mesh, material),
+                });
+            }
+        }
+    }
+    if view.draw_bbox
+        && let Some((lo, hi)) = mesh.bounds()
+    {
+        // Esquina i: el bit 0 elige la X, el 1 la Y y el 2 la Z
+        let corners: Vec<ClipVertex> = (0..8)
+            .map(|i| {
+                let pick = |bit: usize, l: f32, h: f32| if i & bit == 0 { l } else { h };
+                let p = Vec3::new(
+                    pick(1, lo.x, hi.x),
+                    pick(2, lo.y, hi.y),
+                    pick(4, lo.z, hi.z),
+                )
+                .extend(1.0);
+                ClipVertex {
+                    clip: mvp * p,
+                    depth: depth_row.dot(p),
+                }
+            })
+            .collect();
+        // Las 12 aristas unen esquinas que difieren en un solo bit
+        for i in 0..8 {
+            for bit in [1, 2, 4] {
+                if i & bit == 0
+                    && let Some((a, b)) = clip_segment(corners[i], corners[i | bit], &FRUSTUM)
+                {
+                    list.items.push(Primitive::Line {
+                        a: a.to_screen(screenr),
+                        b: b.to_screen(screenr),
+                        width: view.line_width,
+                        color: BBOX_COLOR,
+                    });
+                }
+            }
+        }
+    }
+    if view.draw_vs {
+        for (i, &v) in transformed.iter().enumerate() {
+            if inside(v, &NEAR_FAR) {
+                list.items.push(Primitive::Point {
+                    p: v.to_screen(screenr),
+                    radius: view.point_radius,
+                    color: mesh.colors.get(i).copied().unwrap_or(Color32::LIGHT_RED),
+                });
+            }
+        }
+    }
+    list
+}
+
+// Caras con sombreado plano ordenadas de la más lejana a la más cercana
+// (algoritmo del pintor), para que quien pinte sin búfer de profundidad
+// tape bien lo que queda detrás. Si se dibujan las aristas, cada cara
+// lleva las suyas justo detrás
+fn solid_faces(
+    list: &mut RenderList,
+    mesh: &Mesh,
+    view: &View,
+    transformed: &[ClipVertex],
+    screenr: Rect,
+) {
+    let model = view.model();
+    let (right, up, forward) = view.camera.basis();
+    let d = view.light.direction;
+    let light = (right * d.x + up * d.y - forward * d.z).normalize();
+
+    let mut faces: Vec<(f32, Vec<Primitive>)> = vec![];
+    for f in &mesh.fs {
+        // De los lados ya se encarga quien pinte; sólo hace falta recortar
+        // en profundidad
+        let poly = f.corners.iter().map(|c| transformed[c.v]).collect();
+        let poly = clip_polygon(poly, &NEAR_FAR);
+        if poly.len() < 3 {
+            continue;
+        }
+        let depth = poly.iter().map(|v| v.depth).sum::<f32>() / poly.len() as f32;
+
+        // El modelo sólo se escala por igual en los tres ejes, así que la
+        // normal se transforma como cualquier otra dirección
+        let normal = model.transform_vector(mesh.face_normal(f)).normalize();
+        let first = model.transform_point(mesh.vs[f.corners[0].v]);
+        let k = view
+            .light
+            .intensity(normal, light, view.camera.eye_direction(first));
+        let color = shade(face_color(mesh, f.material), k);
+
+        let poly: Vec<ScreenPoint> = poly.into_iter().map(|v| v.to_screen(screenr)).collect();
+        // Polígonos en abanico de triángulos
+        let mut items: Vec<Primitive> = (1..poly.len() - 1)
+            .map(|i| Primitive::Triangle {
+                vs: [poly[0], poly[i], poly[i + 1]],
+                color,
+            })
+            .collect();
+        if view.draw_fs {
+            let n = f.corners.len();
+            for i in 0..n {
+                let (a, b) = (
+                    transformed[f.corners[i].v],
+                    transformed[f.corners[(i + 1) % n].v],
+                );
+                if let Some((a, b)) = clip_segment(a, b, &FRUSTUM) {
+                    items.push(Primitive::Line {
+                        a: a.to_screen(screenr),
+                        b: b.to_screen(screenr),
+                        width: view.line_width,
+                        color: edge_color(mesh, f.material),
+                    });
+                }
+            }
+        }
+        faces.push((depth, items));
+    }
+    faces.sort_by(|a, b| b.0.total_cmp(&a.0));
+    list.items
+        .extend(faces.into_iter().flat_map(|(_, items)| items));
+}
+
+fn shade(color: Color32, k: f32) -> Color32 {
+    let [r, g, b, a] = color.to_srgba_unmultiplied();
+    let f = |c: u8| (c as f32 * k).round().clamp(0.0, 255.0) as u8;
+    Color32::from_rgba_unmultiplied(f(r), f(g), f(b), a)
+}
+
+// Punto de la malla (en el mundo) que atraviesa primero el rayo: el corte
+// más cercano con sus caras o, en mallas sin caras, el vértice más cercano
+// de los que pasan a menos de `tolerance` del rayo
+pub fn pick(mesh: &Mesh, view: &View, origin: Vec3, dir: Vec3, tolerance: f32) -> Option<Vec3> {
+    let model = view.model();
+    let world: Vec<Vec3> = mesh.vs.iter().map(|&v| model.transform_point(v)).collect();
+    let mut best: Option<f32> = None;
+    let mut closer = |t: f32| {
+        if t > 0.0 && best.is_none_or(|b| t < b) {
+            best = Some(t);
+        }
+    };
+    for f in &mesh.fs {
+        for i in 1..f.corners.len().saturating_sub(1) {
+            let [a, b, c] = [0, i, i + 1].map(|k| world[f.corners[k].v]);
+            if let Some(t) = ray_triangle(origin, dir, a, b, c) {
+                closer(t);
+            }
+        }
+    }
+    if mesh.fs.is_empty() {
+        for &p in &world {
+            let t = (p - origin).dot(dir);
+            if (origin + dir * t - p).length() <= tolerance {
+                closer(t);
+            }
+        }
+    }
+    best.map(|t| origin + dir * t)
+}
+
+// Distancia a lo largo del rayo hasta el triángulo (Möller–Trumbore)
+fn ray_triangle(origin: Vec3, dir: Vec3, a: Vec3, b: Vec3, c: Vec3) -> Option<f32> {
+    let (e1, e2) = (b - a, c - a);
+    let p = dir.cross(e2);
+    let det = e1.dot(p);
+    if det.abs() < 1e-12 {
+        return None;
+    }
+    let s = origin - a;
+    let u = s.dot(p) / det;
+    let q = s.cross(e1);
+    let v = dir.dot(q) / det;
+    (u >= 0.0 && v >= 0.0 && u + v <= 1.0).then(|| e2.dot(q) / det)
+}
+
+// Color de las aristas: el difuso de su material o el de siempre
+fn edge_color(mesh: &Mesh, material: Option<usize>) -> Color32 {
+    material
+        .and_then(|m| mesh.materials.get(m))
+        .map_or(Color32::LIGHT_YELLOW, |m| m.diffuse_color())
+}
+
+fn face_color(mesh: &Mesh, material: Option<usize>) -> Color32 {
+    material
+        .and_then(|m| mesh.materials.get(m))
+        .map_or(Color32::GRAY, |m| m.diffuse_color())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+    use egui::{pos2, vec2};
+
+    // Cámara en el origen mirando hacia -Z
+    fn view() -> View {
+        View {
+            camera: Camera {
+                position: Vec3::ZERO,
+                target: -Vec3::Z,
+                ..Camera::default()
+            },
+            draw_vs: true,
+            solid: true,
+            ..test_utils::view()
+        }
+    }
+
+    fn screen() -> Rect {
+        Rect::from_min_size(pos2(0.0, 0.0), vec2(100.0, 100.0))
+    }
+
+    #[test]
+    fn edges_are_cut_at_the_near_plane() {
+        // Una arista que atraviesa la cámara y otra entera detrás
+        let mut mesh = Mesh::new(
+            vec![
+                Vec3::new(0.0, 0.0, -5.0),
+                Vec3::new(0.0, 0.0, 5.0),
+                Vec3::new(0.0, 0.0, 1.0),
+                Vec3::new(0.0, 0.0, 2.0),
+            ],
+            vec![],
+        );
+        mesh.ls = vec![vec![0, 1], vec![2, 3]];
+        let list = build(&mesh, &view(), screen());
+        let lines: Vec<_> = list
+            .items
+            .iter()
+            .filter_map(|it| match *it {
+                Primitive::Line { a, b, .. } => Some((a, b)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(lines.len(), 1);
+        let (a, b) = lines[0];
+        let near = view().camera.near;
+        assert!((a.depth - 5.0).abs() < 1e-3);
+        assert!((b.depth - near).abs() < 1e-4);
+        // El extremo recortado queda en el centro de la pantalla, sin
+        // saltar al otro lado
+        for p in [a.pos, b.pos] {
+            assert!((p.x - 50.0).abs() < 1e-3 && (p.y - 50.0).abs() < 1e-3);
+        }
+        // Tampoco se pinta ningún vértice detrás de la cámara
+        let points = list
+            .items
+            .iter()
+            .filter(|it| matches!(it, Primitive::Point { .. }))
+            .count();
+        assert_eq!(points, 1);
+    }
+
+    #[test]
+    fn picking_finds_the_nearest_face() {
+        // Dos cuadrados, uno detrás de otro, frente a la cámara
+        let mesh = Mesh::new(
+            vec![
+                Vec3::new(-1.0, -1.0, -3.0),
+                Vec3::new(1.0, -1.0, -3.0),
+                Vec3::new(1.0, 1.0, -3.0),
+                Vec3::new(-1.0, 1.0, -3.0),
+                Vec3::new(-1.0, -1.0, -6.0),
+                Vec3::new(1.0, -1.0, -6.0),
+                Vec3::new(1.0, 1.0, -6.0),
+                Vec3::new(-1.0, 1.0, -6.0),
+            ],
+            vec![vec![4, 5, 6, 7], vec![0, 1, 2, 3]],
+        );
+        let hit = pick(&mesh, &view(), Vec3::ZERO, -Vec3::Z, 0.0).unwrap();
+        assert!((hit - Vec3::new(0.0, 0.0, -3.0)).length() < 1e-5);
+        assert!(pick(&mesh, &view(), Vec3::ZERO, Vec3::X, 0.0).is_none());
+    }
+
+    #[test]
+    fn faces_are_shaded_and_sorted_back_to_front() {
+        // Cuadrado de frente a la luz, cerca, y otro de canto a ella, lejos
+        let mesh = Mesh::new(
+            vec![
+                Vec3::new(-1.0, -1.0, -3.0),
+                Vec3::new(1.0, -1.0, -3.0),
+                Vec3::new(1.0, 1.0, -3.0),
+                Vec3::new(-1.0, 1.0, -3.0),
+                Vec3::new(-1.0, 0.0, -5.0),
+                Vec3::new(1.0, 0.0, -5.0),
+                Vec3::new(1.0, 0.0, -7.0),
+                Vec3::new(-1.0, 0.0, -7.0),
+            ],
+            vec![vec![0, 1, 2, 3], vec![4, 5, 6, 7]],
+        );
+        let view = View {
+            draw_fs: false,
+            draw_vs: false,
+            light: Light {
+                direction: Vec3::Z,
+                ambient: 0.2,
+                diffuse: 0.8,
+            },
+            ..view()
+        };
+        let list = build(&mesh, &view, screen());
+        let tris: Vec<_> = list
+            .items
+            .iter()
+            .filter_map(|it| match *it {
+                Primitive::Triangle { vs, color } => Some((vs[0].depth, color)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(tris.len(), 4);
+        // Primero el lejano, sólo con luz ambiente; después el cercano,
+        // iluminado de lleno
+        assert!(tris[0].0 > tris[3].0);
+        assert_eq!(tris[0].1, shade(Color32::GRAY, 0.2));
+        assert_eq!(tris[3].1, Color32::GRAY);
+    }
+
+    #[test]
+    fn faces_crossing_the_near_plane_are_trimmed() {
+        let mesh = Mesh::new(
+            vec![
+                Vec3::new(-1.0, -1.0, -4.0),
+                Vec3::new(1.0, -1.0, -4.0),
+                Vec3::new(0.0, 1.0, 4.0),
+            ],
+            vec![vec![0, 1, 2]],
+        );
+        let list = build(&mesh, &view(), screen());
+        let tris: Vec<_> = list
+            .items
+            .iter()
+            .filter_map(|it| match *it {
+                Primitive::Triangle { vs, .. } => Some(vs),
+                _ => None,
+            })
+            .collect();
+        // El triángulo recortado es un cuadrilátero: dos triángulos
+        assert_eq!(tris.len(), 2);
+        let near = view().camera.near;
+        assert!(
+            tris.iter()
+                .flatten()
+                .all(|p| p.depth >= near - 1e-4 && p.pos.y.is_finite())
+        );
+    }
+}
@@ -0,0 +1,301 @@
+// Copyright (C) 2026  Antonio-Miguel Corbi Bellot
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+// Exportación a SVG de la vista actual: las mismas aristas y vértices que
+// se pintan en pantalla, como dibujo vectorial
+
+use std::io::{self, Write};
+
+use egui::{Color32, Rect, Vec2, pos2};
+
+use crate::files::create;
+use crate::raster::Canvas;
+use crate::render::{self, Primitive, ScreenPoint, View};
+use crate::types::{Mesh, Point2D};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SvgOptions {
+    // Quita las partes de las aristas tapadas por caras más cercanas
+    pub hidden_lines: bool,
+    pub line_width: f32,
+    // None: el color de cada arista (el de su material)
+    pub line_color: Option<Color32>,
+    pub point_radius: f32,
+    // None: el color de cada vértice
+    pub point_color: Option<Color32>,
+    // None: fondo transparente
+    pub background: Option<Color32>,
+}
+
+impl Default for SvgOptions {
+    fn default() -> Self {
+        Self {
+            hidden_lines: false,
+            line_width: 1.0,
+            line_color: None,
+            point_radius: 1.5,
+            point_color: None,
+            background: None,
+        }
+    }
+}
+
+// Escribe la vista `view` de la malla tal como se vería en `screenr`
+pub fn write_svg(
+    fname: &str,
+    mesh: &Mesh,
+    view: &View,
+    screenr: Rect,
+    opts: &SvgOptions,
+) -> io::Result<()> {
+    let mut w = create(fname)?;
+    write_document(&mut w, mesh, view, screenr.size(), opts)?;
+    w.flush()
+}
+
+fn write_document(
+    w: &mut impl Write,
+    mesh: &Mesh,
+    view: &View,
+    size: Vec2,
+    opts: &SvgOptions,
+) -> io::Result<()> {
+    // Coordenadas del SVG con origen en la esquina del área de dibujo
+    let area = Rect::from_min_size(pos2(0.0, 0.0), size);
+    let view = View {
+        solid: false,
+        line_width: opts.line_width,
+        point_radius: opts.point_radius,
+        ..view.clone()
+    };
+    let list = render::build(mesh, &view, area);
+
+    // Para quitar las líneas ocultas basta con el búfer de profundidad de
+    // las caras, rasterizado al tamaño del dibujo
+    let depth = opts.hidden_lines.then(|| {
+        let faces = View {
+            solid: true,
+            draw_fs: false,
+            draw_vs: false,
+            ..view.clone()
+        };
+        let mut canvas = Canvas::new(size.x as usize, size.y as usize, Color32::TRANSPARENT);
+        canvas.draw(&render::build(mesh, &faces, area));
+        canvas
+    });
+    let depth = depth.as_ref();
+
+    let (width, height) = (num(size.x), num(size.y));
+    writeln!(w, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+    writeln!(
+        w,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}">"#
+    )?;
+    if let Some(bg) = opts.background {
+        writeln!(
+            w,
+            r#"  <rect width="100%" height="100%" fill="{}"{}/>"#,
+            hex(bg),
+            opacity("fill", bg)
+        )?;
+    }
+
+    // Un trazo por color, en el orden en que aparecen
+    let mut paths: Vec<(Color32, String)> = vec![];
+    for item in &list.items {
+        let Primitive::Line { a, b, color, .. } = *item else {
+            continue;
+        };
+        let color = opts.line_color.unwrap_or(color);
+        let spans = match depth {
+            Some(canvas) => visible_spans(canvas, a, b),
+            None => vec![(0.0, 1.0)],
+        };
+        let d = match paths.iter_mut().find(|(c, _)| *c == color) {
+            Some((_, d)) => d,
+            None => {
+                paths.push((color, String::new()));
+                &mut paths.last_mut().unwrap().1
+            }
+        };
+        for (t0, t1) in spans {
+            let (p, q) = (lerp(a.pos, b.pos, t0), lerp(a.pos, b.pos, t1));
+            if !d.is_empty() {
+                d.push(' ');
+            }
+            d.push_str(&format!(
+                "M{} {}L{} {}",
+                num(p.x),
+                num(p.y),
+                num(q.x),
+                num(q.y)
+            ));
+        }
+    }
+    if !paths.is_empty() {
+        writeln!(
+            w,
+            r#"  <g fill="none" stroke-width="{}" stroke-linecap="round">"#,
+            num(opts.line_width)
+        )?;
+        for (color, d) in paths.iter().filter(|(_, d)| !d.is_empty()) {
+            writeln!(
+                w,
+                r#"    <path stroke="{}"{} d="{d}"/>"#,
+                hex(*color),
+                opacity("stroke", *color)
+            )?;
+        }
+        writeln!(w, "  </g>")?;
+    }
+
+    let points: Vec<(ScreenPoint, Color32)> = list
+        .items
+        .iter()
+        .filter_map(|item| match *item {
+            Primitive::Point { p, color, .. } => Some((p, opts.point_color.unwrap_or(color))),
+            _ => None,
+        })
+        .filter(|(p, _)| depth.is_none_or(|c| c.is_visible(p.pos, p.depth)))
+        .collect();
+    if !points.is_empty() {
+        writeln!(w, r#"  <g stroke="none">"#)?;
+        for (p, color) in points {
+            writeln!(
+                w,
+                r#"    <circle cx="{}" cy="{}" r="{}" fill="{}"{}/>"#,
+                num(p.pos.x),
+                num(p.pos.y),
+                num(opts.point_radius),
+                hex(color),
+                opacity("fill", color)
+            )?;
+        }
+        writeln!(w, "  </g>")?;
+    }
+    writeln!(w, "</svg>")
+}
+
+// Tramos (t0, t1) del segmento a→b que no tapa ninguna cara. Se muestrea
+// cada píxel del segmento contra el búfer de profundidad
+fn visible_spans(canvas: &Canvas, a: ScreenPoint, b: ScreenPoint) -> Vec<(f32, f32)> {
+    let len = (b.pos.x - a.pos.x).hypot(b.pos.y - a.pos.y);
+    let n = (len.ceil() as usize).clamp(1, 1 << 16);
+    let mut spans: Vec<(f32, f32)> = vec![];
+    for i in 0..n {
+        let (t0, t1) = (i as f32 / n as f32, (i + 1) as f32 / n as f32);
+        let t = (t0 + t1) / 2.0;
+        let depth = 1.0 / ((1.0 - t) / a.depth + t / b.depth);
+        if !canvas.is_visible(lerp(a.pos, b.pos, t), depth) {
+            continue;
+        }
+        match spans.last_mut() {
+            Some(last) if last.1 == t0 => last.1 = t1,
+            _ => spans.push((t0, t1)),
+        }
+    }
+    spans
+}
+
+fn lerp(p: Point2D, q: Point2D, t: f32) -> Point2D {
+    Point2D {
+        x: p.x + (q.x - p.x) * t,
+        y: p.y + (q.y - p.y) * t,
+    }
+}
+
+// Números cortos: dos decimales y sin ceros sobrantes
+fn num(v: f32) -> String {
+    let s = format!("{v:.2}");
+    let s = s.trim_end_matches('0').trim_end_matches('.');
+    if s == "-0" {
+        "0".to_string()
+    } else {
+        s.to_string()
+    }
+}
+
+fn hex(c: Color32) -> String {
+    let [r, g, b, _] = c.to_srgba_unmultiplied();
+    format!("#{r:02x}{g:02x}{b:02x}")
+}
+
+fn opacity(attr: &str, c: Color32) -> String {
+    let a = c.to_srgba_unmultiplied()[3];
+    if a == 255 {
+        String::new()
+    } else {
+        format!(r#" {attr}-opacity="{}""#, num(a as f32 / 255.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::view;
+    use crate::types::Point3D;
+
+    // Cuadrado de frente y, detrás, una línea que lo cruza de lado a lado
+    fn scene() -> Mesh {
+        let p = |x, y, z| Point3D { x, y, z };
+        let mut mesh = Mesh::new(
+            vec![
+                p(-0.5, -0.5, 0.0),
+                p(0.5, -0.5, 0.0),
+                p(0.5, 0.5, 0.0),
+                p(-0.5, 0.5, 0.0),
+                p(-1.0, 0.0, -1.0),
+                p(1.0, 0.0, -1.0),
+            ],
+            vec![vec![0, 1, 2, 3]],
+        );
+        mesh.ls.push(vec![4, 5]);
+        mesh
+    }
+
+    fn export(opts: &SvgOptions) -> String {
+        let mut out = vec![];
+        write_document(&mut out, &scene(), &view(), egui::vec2(200.0, 100.0), opts).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn segments_become_paths() {
+        let svg = export(&SvgOptions {
+            line_color: Some(Color32::RED),
+            line_width: 2.0,
+            ..SvgOptions::default()
+        });
+        assert!(svg.starts_with("<?xml"));
+        assert!(svg.contains(r#"viewBox="0 0 200 100""#));
+        assert!(svg.contains(r#"stroke-width="2""#));
+        // Todas las aristas en un único trazo rojo: 4 del cuadrado y la línea
+        assert_eq!(svg.matches("<path").count(), 1);
+        assert_eq!(svg.matches('M').count(), 5);
+        assert!(svg.contains(r##"stroke="#ff0000""##));
+    }
+
+    #[test]
+    fn hidden_lines_are_split() {
+        let svg = export(&SvgOptions {
+            hidden_lines: true,
+            line_color: Some(Color32::RED),
+            ..SvgOptions::default()
+        });
+        // La línea de detrás queda partida en dos tramos a los lados del
+        // cuadrado; sus aristas siguen viéndose
+        assert_eq!(svg.matches('M').count(), 6);
+    }
+}
@@ -0,0 +1,68 @@
+// Copyright (C) 2026  Antonio-Miguel Corbi Bellot
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+// Utilidades comunes a las pruebas de todos los módulos
+
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::camera::Camera;
+use crate::math::Vec3;
+use crate::render::{Light, View};
+
+// Directorio temporal de una prueba. Cada uno es distinto aunque las
+// pruebas corran en paralelo, y al soltarlo se borra con todo lo que se
+// haya escrito dentro (también los ficheros que acompañan a otro, como un
+// .mtl o los fotogramas numerados)
+pub struct TempDir(PathBuf);
+
+impl TempDir {
+    pub fn new() -> Self {
+        static NEXT: AtomicUsize = AtomicUsize::new(0);
+        let n = NEXT.fetch_add(1, Ordering::Relaxed);
+        let dir = std::env::temp_dir().join(format!("formulars-{}-{n}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        Self(dir)
+    }
+
+    // Ruta de un fichero dentro del directorio
+    pub fn file(&self, name: &str) -> String {
+        self.0.join(name).to_string_lossy().into_owned()
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+
+// Vista de alambre sin giros, con el modelo en el origen a 5 unidades de la
+// cámara
+pub fn view() -> View {
+    View {
+        center: Vec3::ZERO,
+        scale: 1.0,
+        rotation: vec![],
+        camera: Camera::looking_at(Vec3::ZERO, 5.0),
+        draw_vs: false,
+        draw_fs: true,
+        draw_bbox: false,
+        solid: false,
+        light: Light::default(),
+        line_width: 1.0,
+        point_radius: 1.0,
+    }
+}
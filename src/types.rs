@@ -13,9 +13,12 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <http://www.gnu.org/licenses/>.
 
-use egui::Rect;
+use egui::{Color32, Rect};
 
-pub type Line = [u32; 3];
+use crate::math::Vec3;
+
+// Cara de un modelo embebido: índices de sus vértices (3 o más)
+pub type Line = &'static [u32];
 
 // pub type Lines = Vec<Line>;
 // Cambiamos el tipo a una referencia de array o array fijo
@@ -27,22 +30,78 @@ pub struct Point2D {
     pub y: f32,
 }
 
-#[derive(Debug, Copy, Clone)]
-pub struct Point3D {
-    pub x: f32,
-    pub y: f32,
-    pub z: f32,
-}
+pub type Point3D = Vec3;
 
 pub type Points = &'static [Point3D];
 
+// Esquina de una cara: índices (base 0) de posición, textura y normal
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Corner {
+    pub v: usize,
+    pub vt: Option<usize>,
+    pub vn: Option<usize>,
+}
+
+// Cara poligonal con el grupo, el grupo de suavizado y el material con
+// que se definió
+#[derive(Debug, Clone, PartialEq)]
+pub struct Face {
+    pub corners: Vec<Corner>,
+    pub group: Option<usize>,
+    pub smoothing: u32,
+    pub material: Option<usize>,
+}
+
+// Material de una biblioteca MTL (colores RGB en [0, 1])
+#[derive(Debug, Clone, PartialEq)]
+pub struct Material {
+    pub name: String,
+    pub ka: [f32; 3],
+    pub kd: [f32; 3],
+    pub ks: [f32; 3],
+    pub ns: f32,
+    pub d: f32,
+    pub map_kd: Option<String>,
+}
+
+// Malla cargada en tiempo de ejecución
+#[derive(Debug, Clone, Default)]
+pub struct Mesh {
+    pub vs: Vec<Point3D>,
+    // Coordenada w de cada vértice; vacío si todas valen 1.0
+    pub ws: Vec<f32>,
+    pub uvs: Vec<Point2D>,
+    pub ns: Vec<Point3D>,
+    // Color de cada vértice; vacío si el modelo no los trae
+    pub colors: Vec<Color32>,
+    pub fs: Vec<Face>,
+    // Polilíneas (elementos "l"), como índices de posición
+    pub ls: Vec<Vec<usize>>,
+    // Nombres de los grupos/objetos ("g"/"o")
+    pub groups: Vec<String>,
+    pub materials: Vec<Material>,
+}
+
 // Definimos los ejes posibles
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Axe {
     X,
     Y,
     Z,
 }
 
+impl Axe {
+    // Vector unitario en la dirección del eje
+    pub fn unit(self) -> Point3D {
+        let (x, y, z) = match self {
+            Axe::X => (1.0, 0.0, 0.0),
+            Axe::Y => (0.0, 1.0, 0.0),
+            Axe::Z => (0.0, 0.0, 1.0),
+        };
+        Point3D { x, y, z }
+    }
+}
+
 impl Point2D {
     pub fn world2screen(&self, world: Rect, screen: Rect) -> Point2D {
         let x = egui::remap(
@@ -59,56 +118,204 @@ impl Point2D {
     }
 }
 
-impl Point3D {
-    pub fn convert_to_2D(&self, dz: f32, worldr: &Rect, screenr: &Rect) -> Point2D {
-        self.translate_z(dz)
-            .project()
-            .world2screen(*worldr, *screenr)
+impl Corner {
+    pub fn new(v: usize) -> Self {
+        Self {
+            v,
+            vt: None,
+            vn: None,
+        }
+    }
+}
+
+impl Face {
+    // Cara sin atributos a partir de sus índices de posición
+    pub fn from_indices(vs: &[usize]) -> Self {
+        Self {
+            corners: vs.iter().map(|&v| Corner::new(v)).collect(),
+            group: None,
+            smoothing: 0,
+            material: None,
+        }
+    }
+}
+
+impl Material {
+    // Valores por defecto de la especificación MTL
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            ka: [0.2, 0.2, 0.2],
+            kd: [0.8, 0.8, 0.8],
+            ks: [1.0, 1.0, 1.0],
+            ns: 0.0,
+            d: 1.0,
+            map_kd: None,
+        }
+    }
+
+    pub fn diffuse_color(&self) -> Color32 {
+        let [r, g, b] = self.kd.map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u8);
+        let a = (self.d.clamp(0.0, 1.0) * 255.0).round() as u8;
+        Color32::from_rgba_unmultiplied(r, g, b, a)
+    }
+}
+
+impl Mesh {
+    pub fn new(vs: Vec<Point3D>, fs: Vec<Vec<usize>>) -> Self {
+        Self {
+            vs,
+            fs: fs.iter().map(|f| Face::from_indices(f)).collect(),
+            ..Default::default()
+        }
+    }
+
+    // Construye la malla a partir de los arrays estáticos (p.e. Penger)
+    pub fn from_static(vs: Points, fs: Lines) -> Self {
+        let fs = fs
+            .iter()
+            .map(|f| f.iter().map(|&i| i as usize).collect())
+            .collect();
+        Self::new(vs.to_vec(), fs)
+    }
+
+    // Modelo por defecto: el Penger embebido
+    pub fn penger() -> Self {
+        use crate::models::penger;
+        Self::from_static(penger::VS, penger::FS)
+    }
+
+    // Modelo embebido por nombre (ver assets/models/models.txt)
+    pub fn builtin(name: &str) -> Option<Self> {
+        crate::models::MODELS
+            .iter()
+            .find(|(n, _, _)| *n == name)
+            .map(|&(_, vs, fs)| Self::from_static(vs, fs))
     }
 
-    pub fn translate_z(&self, dz: f32) -> Self {
-        Point3D {
-            x: self.x,
-            y: self.y,
-            z: self.z + dz,
+    // Pares de vértices a unir: aristas de cada cara (cerradas) y
+    // segmentos de cada polilínea (abiertas), junto con su material
+    pub fn segments(&self) -> impl Iterator<Item = (usize, usize, Option<usize>)> + '_ {
+        let faces = self.fs.iter().flat_map(|f| {
+            let n = f.corners.len();
+            (0..n).map(move |i| (f.corners[i].v, f.corners[(i + 1) % n].v, f.material))
+        });
+        let lines = self
+            .ls
+            .iter()
+            .flat_map(|l| l.windows(2).map(|w| (w[0], w[1], None)));
+        faces.chain(lines)
+    }
+
+    // Normal unitaria de la cara por el método de Newell (válido también
+    // para polígonos no planos); (0, 0, 0) si la cara es degenerada
+    pub fn face_normal(&self, f: &Face) -> Point3D {
+        let (mut x, mut y, mut z) = (0.0, 0.0, 0.0);
+        let n = f.corners.len();
+        for i in 0..n {
+            let a = self.vs[f.corners[i].v];
+            let b = self.vs[f.corners[(i + 1) % n].v];
+            x += (a.y - b.y) * (a.z + b.z);
+            y += (a.z - b.z) * (a.x + b.x);
+            z += (a.x - b.x) * (a.y + b.y);
         }
+        Vec3::new(x, y, z).normalize()
+    }
+
+    // ¿Se pueden guardar normales/uvs por vértice? Sólo si hay uno por
+    // vértice y todas las esquinas usan el de su propio vértice
+    pub fn has_vertex_normals(&self) -> bool {
+        self.ns.len() == self.vs.len()
+            && self
+                .fs
+                .iter()
+                .flat_map(|f| &f.corners)
+                .all(|c| c.vn.is_none_or(|n| n == c.v))
     }
 
-    pub fn project(&self) -> Point2D {
-        let d = 5.0;
-        let pval = d / self.z;
-        Point2D {
-            x: self.x * pval,
-            y: self.y * pval,
+    pub fn has_vertex_uvs(&self) -> bool {
+        self.uvs.len() == self.vs.len()
+            && self
+                .fs
+                .iter()
+                .flat_map(|f| &f.corners)
+                .all(|c| c.vt.is_none_or(|t| t == c.v))
+    }
+
+    // Esquinas mínima y máxima de la caja que contiene a los vértices
+    pub fn bounds(&self) -> Option<(Point3D, Point3D)> {
+        let first = *self.vs.first()?;
+        Some(
+            self.vs
+                .iter()
+                .fold((first, first), |(lo, hi), &v| (lo.min(v), hi.max(v))),
+        )
+    }
+
+    pub fn centroid(&self) -> Option<Point3D> {
+        let n = self.vs.len();
+        (n > 0).then(|| self.vs.iter().fold(Vec3::ZERO, |s, &v| s + v) / n as f32)
+    }
+
+    // Esfera centrada en el centroide que contiene a todos los vértices
+    pub fn bounding_sphere(&self) -> Option<(Point3D, f32)> {
+        let center = self.centroid()?;
+        let radius = self
+            .vs
+            .iter()
+            .map(|&v| (v - center).length())
+            .fold(0.0, f32::max);
+        Some((center, radius))
+    }
+
+    // Centro (el centroide de los vértices) y escala que dejan el modelo
+    // dentro de una esfera de ese radio, sea cual sea su tamaño original
+    pub fn fit(&self, radius: f32) -> (Point3D, f32) {
+        match self.bounding_sphere() {
+            Some((center, r)) if r > 0.0 => (center, radius / r),
+            Some((center, _)) => (center, 1.0),
+            None => (Vec3::ZERO, 1.0),
         }
     }
 
-    // Función que recibe el punto, el ángulo y el eje de rotación
-    pub fn rotate(&self, angle: f32, axe: Axe) -> Point3D {
-        let angle = angle.to_radians();
-        let Point3D { x, y, z } = *self;
-        let cos_a = angle.cos();
-        let sin_a = angle.sin();
-
-        match axe {
-            Axe::X => {
-                // X se queda igual
-                let y = y * cos_a - z * sin_a;
-                let z = y * sin_a + z * cos_a;
-                Point3D { x, y, z }
-            }
-            Axe::Y => {
-                // Y se queda igual
-                let x = x * cos_a + z * sin_a;
-                let z = -x * sin_a + z * cos_a;
-                Point3D { x, y, z }
-            }
-            Axe::Z => {
-                // Z se queda igual
-                let x = x * cos_a - y * sin_a;
-                let y = x * sin_a + y * cos_a;
-                Point3D { x, y, z }
-            }
+    // Índice del material con ese nombre, creándolo si no existe
+    pub fn material_index(&mut self, name: &str) -> usize {
+        self.materials
+            .iter()
+            .position(|m| m.name == name)
+            .unwrap_or_else(|| {
+                self.materials.push(Material::new(name));
+                self.materials.len() - 1
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Los modelos embebidos conservan los polígonos igual que el lector
+    // de OBJ en tiempo de ejecución
+    #[test]
+    fn builtin_models_match_the_obj_loader() {
+        for (name, obj) in [("cube", "cube.obj"), ("penger", "penger.obj")] {
+            let builtin = Mesh::builtin(name).unwrap();
+            let fname = format!("{}/assets/models/{obj}", env!("CARGO_MANIFEST_DIR"));
+            let loaded = crate::files::read_obj(&fname).unwrap();
+            assert_eq!(builtin.vs.len(), loaded.vs.len(), "{name}");
+            let indices = |m: &Mesh| -> Vec<Vec<usize>> {
+                m.fs.iter()
+                    .map(|f| f.corners.iter().map(|c| c.v).collect())
+                    .collect()
+            };
+            assert_eq!(indices(&builtin), indices(&loaded), "{name}");
         }
+        assert!(
+            Mesh::builtin("cube")
+                .unwrap()
+                .fs
+                .iter()
+                .all(|f| f.corners.len() == 4)
+        );
     }
 }